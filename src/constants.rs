@@ -1,5 +1,19 @@
 pub const ENQUEUE_PRICE: u64 = 1_000_000_000_000_000; // 0.001 EGLD
 pub const UNEQUIPPED_ITEM_NAME: &[u8] = b"unequipped";
+/// Unlock timestamp of a slot that can never be unequipped.
+pub const PERMANENT_LOCK: u64 = u64::MAX;
+pub const PERMANENT_LOCK_NAME: &[u8] = b"permanent";
+pub const MAX_FEE_SHARES: u64 = 10_000;
+pub const PAUSABLE_ENDPOINTS: [&[u8]; 8] = [
+    b"customize",
+    b"customizeBatch",
+    b"customizeFor",
+    b"swap",
+    b"applyLoadout",
+    b"renderImage",
+    b"setUriOfAttributes",
+    b"refreshUri",
+];
 
 pub const ERR_CANNOT_REGISTER_EQUIPPABLE_AS_ITEM: &str =
     "You cannot register an equippable NFT as an item.";
@@ -31,3 +45,110 @@ pub const ERR_CANNOT_FILL_UNREGISTERED_ITEM: &str =
     "An item must be registered before calling the fill endpoint.";
 pub const ERR_IMAGE_NOT_IN_RENDER_QUEUE: &str =
     "cannot set the uri because the attributes are not in the render queue";
+pub const ERR_ITEM_NOT_REGISTERED: &str = "The item is not registered.";
+pub const ERR_CANNOT_REGISTER_REMAPPED_ITEM: &str =
+    "This item has been remapped to another one, it cannot be registered again.";
+pub const ERR_SLOT_NOT_REGISTERED: &str = "The slot is not registered.";
+pub const ERR_SLOT_HAS_REGISTERED_ITEMS: &str =
+    "Some items are registered in this slot. Unregister them first.";
+pub const ERR_TOO_MANY_SLOTS_EQUIPPED: &str =
+    "The equippable has more items equipped than the maximum allowed.";
+pub const ERR_EXCLUSION_GROUP_NEEDS_TWO_SLOTS: &str =
+    "An exclusion group must contain at least two slots.";
+pub const ERR_EXCLUSION_GROUP_NOT_FOUND: &str = "There is no exclusion group with this name.";
+pub const ERR_ITEM_CANNOT_REQUIRE_ITS_OWN_SLOT: &str = "An item cannot require its own slot.";
+pub const ERR_ITEM_REQUIREMENT_NOT_FOUND: &str = "The item does not have this requirement.";
+pub const ERR_ITEM_REQUIREMENT_NOT_MET: &str =
+    "An item you are equipping requires another item to be equipped.";
+pub const ERR_CANNOT_UNEQUIP_REQUIRED_ITEM: &str =
+    "You cannot unequip an item required by another item. Unequip the other item too.";
+pub const ERR_SLOT_IS_NOT_MANDATORY: &str = "The slot has no default item.";
+pub const ERR_CANNOT_UNEQUIP_MANDATORY_SLOT: &str =
+    "This slot is mandatory. Equip another item instead of unequipping it.";
+pub const ERR_TOKEN_DOES_NOT_REPRESENT_ITEM: &str = "The token does not represent this item.";
+pub const ERR_UNKNOWN_EQUIPPABLE_COLLECTION: &str =
+    "This collection is not managed by the contract.";
+pub const ERR_EQUIPPABLE_COLLECTION_ALREADY_ADDED: &str =
+    "This collection is already managed by the contract.";
+pub const ERR_COLLECTION_HAS_REGISTERED_ITEMS: &str =
+    "Some items are registered for this collection. Unregister them first.";
+pub const ERR_COLLECTION_HAS_PENDING_RENDERS: &str =
+    "Some renders or customizations held in escrow are pending for this collection.";
+pub const ERR_CANNOT_REGISTER_ITEM_AS_EQUIPPABLE: &str =
+    "This token is registered as an item, it cannot be used as an equippable.";
+pub const ERR_ATTRIBUTES_DO_NOT_MATCH_COLLECTION_SLOTS: &str =
+    "The attributes do not match the slots of the collection.";
+pub const ERR_NOT_ENOUGH_AVAILABLE_STOCK: &str =
+    "Not enough stock available. Equipped items cannot be withdrawn.";
+pub const ERR_LEGACY_INVENTORY_MIGRATION_CLOSED: &str =
+    "The legacy inventory can only be migrated once, before any deposit or customization.";
+pub const ERR_LEGACY_EQUIPPED_ABOVE_BALANCE: &str =
+    "The equipped quantity is greater than the legacy tokens held.";
+pub const ERR_NOT_A_SUPPLIER: &str = "Only the owner and the approved suppliers can fill items.";
+pub const ERR_SUPPLIER_NOT_FOUND: &str = "This address is not a supplier.";
+pub const ERR_TOKEN_NOT_ASSIGNED_TO_SUPPLIER: &str = "This token is not assigned to you.";
+pub const ERR_NOT_ENOUGH_SUPPLIER_DEPOSITS: &str = "You cannot withdraw more than you deposited.";
+pub const ERR_ADD_QUANTITY_ROLE_NOT_SET_FOR_ITEM: &str =
+    "The smart contract must have the NftAddQuantity role on the item to mint it.";
+pub const ERR_BURN_ROLE_NOT_SET_FOR_ITEM: &str =
+    "The smart contract must have the NftBurn role on the item to burn it.";
+pub const ERR_SWAP_NEEDS_TWO_EQUIPPABLES: &str =
+    "You must send two equippables of the same collection to swap their items.";
+pub const ERR_NEED_ONE_SLOT_TO_SWAP: &str = "You must set at least one slot to swap.";
+pub const ERR_CANNOT_SWAP_EMPTY_SLOTS: &str =
+    "Both equippables have nothing equipped in this slot.";
+pub const ERR_CONFLICTING_SLOTS_EQUIPPED: &str =
+    "An item is equipped in a slot excluded by another equipped slot.";
+pub const ERR_NEED_ONE_BATCH_INSTRUCTION: &str = "You must customize at least one equippable.";
+pub const ERR_BATCH_PAYMENTS_DO_NOT_MATCH_INSTRUCTIONS: &str =
+    "The tokens sent do not match the number of items of each instruction.";
+pub const ERR_VAULT_ACCEPTS_ONLY_ITEMS: &str =
+    "Only registered items can be deposited in the vault.";
+pub const ERR_NOT_ENOUGH_IN_VAULT: &str = "You cannot withdraw more than you have in your vault.";
+pub const ERR_LOADOUT_NOT_FOUND: &str = "You have no loadout with this name.";
+pub const ERR_LOADOUT_PAYMENT_NOT_USED: &str =
+    "An item sent is not needed by the loadout. Only send the missing items.";
+pub const ERR_EQUIPPABLE_NOT_OWNED: &str = "The address does not hold this equippable.";
+pub const ERR_UPDATE_ATTRIBUTES_ROLE_NOT_SET_FOR_EQUIPPABLE: &str =
+    "This smart contract lacks the update attributes role in the collection of equipable NFTs.";
+pub const ERR_ADD_URI_ROLE_NOT_SET_FOR_EQUIPPABLE: &str =
+    "This smart contract lacks the add URI role in the collection of equipable NFTs.";
+pub const ERR_GENERATED_URI_NOT_FOUND: &str = "This generated URI is not set for the collection.";
+pub const ERR_GENERATED_URI_PREFIX_EMPTY: &str = "The prefix of a generated URI cannot be empty.";
+pub const ERR_PROXY_NOT_FOUND: &str = "This address is not a proxy.";
+pub const ERR_INVALID_RECEIVER: &str =
+    "The receiver cannot be the zero address or the smart contract.";
+pub const ERR_NOT_ALLOWED_TO_SET_RECEIVER: &str =
+    "Only the proxies can send the customized equippable to another address.";
+pub const ERR_FEE_SHARE_IS_ZERO: &str = "The share of a beneficiary cannot be zero.";
+pub const ERR_FEE_BENEFICIARY_DUPLICATED: &str = "A beneficiary cannot be listed twice.";
+pub const ERR_FEE_SHARES_MUST_ADD_UP_TO_TOTAL: &str =
+    "The shares of the beneficiaries must add up to 10 000.";
+pub const ERR_NEED_FEE_CREDIT_PAYMENT: &str = "You must send EGLD or an ESDT to deposit.";
+pub const ERR_FEE_CREDIT_WITHDRAWAL_IS_ZERO: &str =
+    "The amount to withdraw must be greater than zero.";
+pub const ERR_FEE_SCHEDULE_NOT_FOUND: &str = "This token is not accepted to pay the fee.";
+pub const ERR_NOT_ENOUGH_FEE_CREDIT: &str =
+    "Not enough fee credit to pay the customization. Deposit it with depositFeeCredit first.";
+pub const ERR_INVALID_PAYMENT_TOKEN: &str = "The payment token must be EGLD or a valid ESDT.";
+pub const ERR_RENDER_PRICE_NOT_FOUND: &str = "This token is not accepted to render images.";
+pub const ERR_TREASURY_EMPTY: &str = "There is nothing to claim in this token.";
+pub const ERR_WRONG_RENDER_PAYMENT: &str =
+    "You must pay the render with one of the accepted tokens, at its price.";
+pub const ERR_EQUIPPABLE_COOLDOWN_NOT_ELAPSED: &str =
+    "This equippable has been customized too recently. See getNextCustomizationTime.";
+pub const ERR_ADDRESS_COOLDOWN_NOT_ELAPSED: &str =
+    "You customized an equippable too recently. See getNextCustomizationTime.";
+pub const ERR_PAUSER_NOT_FOUND: &str = "This address is not a pauser.";
+pub const ERR_NOT_OWNER_OR_PAUSER: &str = "Only the owner and the pausers can pause.";
+pub const ERR_ENDPOINT_NOT_PAUSABLE: &str = "This endpoint cannot be paused.";
+pub const ERR_CONTRACT_PAUSED: &str = "The contract is paused.";
+pub const ERR_ENDPOINT_PAUSED: &str = "This endpoint is paused.";
+pub const ERR_NO_PLACEHOLDER_TO_REPLACE: &str =
+    "The image of this equippable is not a placeholder.";
+pub const ERR_IMAGE_NOT_RENDERED_YET: &str =
+    "The image of this equippable is not rendered yet. Try again later.";
+pub const ERR_PENDING_CUSTOMIZATION_NOT_FOUND: &str =
+    "There is no customization pending for this equippable.";
+pub const ERR_NOT_PENDING_CUSTOMIZATION_OWNER: &str =
+    "Only the address that customized the equippable can cancel it.";
@@ -17,9 +17,13 @@ use crate::{
     structs::{
         equippable_attributes::{
             panic_if_name_contains_unsupported_characters,
-            panic_if_slot_contains_unsupported_characters,
+            panic_if_slot_contains_unsupported_characters, EquippableAttributes,
         },
+        item_requirement::ItemRequirement,
+        registry_change::{RegistryChange, RegistryChangeKind},
+        supply_mode::SupplyMode,
         token::Token,
+        update_mode::UpdateMode,
     },
 };
 
@@ -27,62 +31,476 @@ pub const ERR_BAD_ROYALTIES: &str = "The royalties must be between 0 and 10000";
 
 #[elrond_wasm::derive::contract]
 pub trait Equip:
-    customize::CustomizeModule + storage::StorageModule + equippable_uris::EquippableUrisModule
+    customize::CustomizeModule
+    + storage::StorageModule
+    + equippable_uris::EquippableUrisModule
+    + uris::UrisModule
+    + customization::CustomizationModule
+    + slots::SlotsModule
+    + requirements::RequirementsModule
+    + inventory::InventoryModule
+    + suppliers::SuppliersModule
+    + vault::VaultModule
+    + loadouts::LoadoutsModule
+    + simulation::SimulationModule
+    + fees::FeesModule
+    + treasury::TreasuryModule
+    + cooldowns::CooldownsModule
+    + pause::PauseModule
+    + proxies::ProxiesModule
+    + escrow::EscrowModule
+    + preview::PreviewModule
 {
     #[init]
     fn init(&self, equippable_token_id: TokenIdentifier) {
-        self.equippable_token_id().set(&equippable_token_id);
+        self.equippable_token_ids().insert(equippable_token_id);
+    }
+
+    /// Manage another collection of equippables. Each collection has its own items and slots.
+    #[endpoint(addEquippableCollection)]
+    #[only_owner]
+    fn add_equippable_collection(&self, equippable_token_id: TokenIdentifier) {
+        require!(
+            self.is_item_token_id(&equippable_token_id) == false,
+            ERR_CANNOT_REGISTER_ITEM_AS_EQUIPPABLE
+        );
+
+        require!(
+            self.equippable_token_ids().insert(equippable_token_id),
+            ERR_EQUIPPABLE_COLLECTION_ALREADY_ADDED
+        );
+    }
+
+    /// Stop managing a collection, and forget its settings.
+    /// Its items must be unregistered, and its renders and customizations held in escrow completed first.
+    #[endpoint(removeEquippableCollection)]
+    #[only_owner]
+    fn remove_equippable_collection(&self, equippable_token_id: TokenIdentifier) {
+        self.require_equippable_collection(&equippable_token_id);
+        require!(
+            self.registered_items(&equippable_token_id).is_empty(),
+            ERR_COLLECTION_HAS_REGISTERED_ITEMS
+        );
+        require!(
+            self.attributes_to_render_by_name(&equippable_token_id)
+                .is_empty()
+                && self
+                    .pending_customizations_by_collection(&equippable_token_id)
+                    .is_empty(),
+            ERR_COLLECTION_HAS_PENDING_RENDERS
+        );
+
+        self.equippable_token_ids()
+            .swap_remove(&equippable_token_id);
+        self.update_mode(&equippable_token_id).clear();
+        self.consumable_items(&equippable_token_id).clear();
+        self.slots(&equippable_token_id).clear();
+        self.max_slots_per_equippable(&equippable_token_id).clear();
+        self.exclusion_groups(&equippable_token_id).clear();
+        self.default_items(&equippable_token_id).clear();
+        self.fee_schedules(&equippable_token_id).clear();
+        self.generated_uris(&equippable_token_id).clear();
+        self.placeholder_uri(&equippable_token_id).clear();
+        self.placeholder_nonces(&equippable_token_id).clear();
+        self.equippable_cooldown(&equippable_token_id).clear();
+        self.address_cooldown(&equippable_token_id).clear();
+        self.escrow_enabled(&equippable_token_id).clear();
+    }
+
+    /// Choose between reminting the equippables on customization, or updating them in place.
+    /// Updating in place requires the NftUpdateAttributes and NftAddUri roles on the collection.
+    /// URIs cannot be removed in place: each customization adds one, and marketplaces keep showing the first one.
+    #[endpoint(setUpdateMode)]
+    #[only_owner]
+    fn set_update_mode(&self, equippable_token_id: TokenIdentifier, update_mode: UpdateMode) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        self.update_mode(&equippable_token_id).set(update_mode);
+
+        self.require_equippable_collection_roles_set(&equippable_token_id);
+    }
+
+    #[view(getUpdateMode)]
+    fn get_update_mode(&self, equippable_token_id: TokenIdentifier) -> UpdateMode {
+        return self.update_mode(&equippable_token_id).get();
+    }
+
+    #[view(getEquippableCollections)]
+    fn get_equippable_collections(&self) -> MultiValueEncoded<TokenIdentifier> {
+        let mut output = MultiValueEncoded::new();
+
+        for equippable_token_id in self.equippable_token_ids().iter() {
+            output.push(equippable_token_id);
+        }
+
+        return output;
     }
 
     #[endpoint(registerItem)]
     #[only_owner]
     fn register_item(
         &self,
+        equippable_token_id: TokenIdentifier,
         items: MultiValueEncoded<
             Self::Api,
             MultiValue4<ManagedBuffer<Self::Api>, ManagedBuffer, TokenIdentifier, u64>,
         >,
     ) {
+        self.require_equippable_collection(&equippable_token_id);
+
         for item in items.into_iter() {
             let (slot, name, token_id, token_nonce) = item.into_tuple();
 
             require!(
-                token_id != self.equippable_token_id().get(),
+                self.equippable_token_ids().contains(&token_id) == false,
                 ERR_CANNOT_REGISTER_EQUIPPABLE_AS_ITEM
             );
 
             panic_if_name_contains_unsupported_characters(&Option::Some(name.clone()));
             panic_if_slot_contains_unsupported_characters(&slot);
+            self.require_slot_registered(&equippable_token_id, &slot);
 
             let item = Item { name, slot };
             let token = Token::new(token_id, token_nonce);
 
-            let is_insert_successful = self.map_items_tokens().insert(item, token);
+            require!(
+                self.remapped_items(&equippable_token_id, &item).is_empty(),
+                ERR_CANNOT_REGISTER_REMAPPED_ITEM
+            );
+
+            let is_insert_successful = self.insert_item_token(&equippable_token_id, &item, &token);
 
             require!(is_insert_successful, ERR_CANNOT_OVERRIDE_REGISTERED_ITEM);
         }
     }
 
+    /// Move the items registered before the upgrade into the registry of the collection.
+    #[endpoint(migrateLegacyItems)]
+    #[only_owner]
+    fn migrate_legacy_items(&self, equippable_token_id: TokenIdentifier) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        let mut legacy_items = ManagedVec::<Self::Api, Item<Self::Api>>::new();
+
+        for (item, token) in self.legacy_items_tokens().iter() {
+            let is_insert_successful = self.insert_item_token(&equippable_token_id, &item, &token);
+            require!(is_insert_successful, ERR_CANNOT_OVERRIDE_REGISTERED_ITEM);
+
+            legacy_items.push(item);
+        }
+
+        for item in legacy_items.iter() {
+            self.legacy_items_tokens().remove_by_id(&item);
+        }
+    }
+
+    /// Remove an item from the registry.
+    /// Equippables still carrying it can unequip it; its token is sent back while it can still be supplied.
+    #[endpoint(unregisterItem)]
+    #[only_owner]
+    fn unregister_item(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+    ) {
+        let item = Item { name, slot };
+
+        let opt_token = self.get_token(&equippable_token_id, &item);
+        require!(opt_token.is_some(), ERR_ITEM_NOT_REGISTERED);
+
+        self.remove_item(&equippable_token_id, &item);
+
+        self.registry_changes().push(&RegistryChange {
+            equippable_token_id,
+            kind: RegistryChangeKind::Unregister,
+            old_item: item,
+            old_token: opt_token.unwrap(),
+            new_item: Option::None,
+            new_token: Option::None,
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+    }
+
+    /// Replace a registered item by another name, slot or token.
+    /// The new item is only represented by the token provided; other tokens can be added back through `registerItem`.
+    /// Equippables carrying the old name are migrated to the new one during their next customization.
+    /// If the item moves to another slot, they keep the old item, which can still be unequipped as an unregistered item.
+    #[endpoint(remapItem)]
+    #[only_owner]
+    fn remap_item(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        new_slot: ManagedBuffer,
+        new_name: ManagedBuffer,
+        new_token_id: TokenIdentifier,
+        new_token_nonce: u64,
+    ) {
+        require!(
+            self.equippable_token_ids().contains(&new_token_id) == false,
+            ERR_CANNOT_REGISTER_EQUIPPABLE_AS_ITEM
+        );
+
+        panic_if_name_contains_unsupported_characters(&Option::Some(new_name.clone()));
+        panic_if_slot_contains_unsupported_characters(&new_slot);
+        self.require_slot_registered(&equippable_token_id, &new_slot);
+
+        let old_item = Item { name, slot };
+        let new_item = Item {
+            name: new_name,
+            slot: new_slot,
+        };
+        let new_token = Token::new(new_token_id, new_token_nonce);
+
+        let opt_old_token = self.get_token(&equippable_token_id, &old_item);
+        require!(opt_old_token.is_some(), ERR_ITEM_NOT_REGISTERED);
+
+        let supply_mode = self.supply_mode(&equippable_token_id, &old_item).get();
+        let is_consumable = self
+            .consumable_items(&equippable_token_id)
+            .contains(&old_item);
+        let lock_duration = self
+            .item_lock_duration(&equippable_token_id, &old_item)
+            .get();
+        let mut requirements = ManagedVec::<Self::Api, ItemRequirement<Self::Api>>::new();
+        for requirement in self
+            .item_requirements(&equippable_token_id, &old_item)
+            .iter()
+        {
+            requirements.push(requirement);
+        }
+
+        // a remapped item cannot be the target of a remap, so the remaps never loop
+        require!(
+            self.remapped_items(&equippable_token_id, &new_item)
+                .is_empty(),
+            ERR_CANNOT_REGISTER_REMAPPED_ITEM
+        );
+
+        self.remove_item(&equippable_token_id, &old_item);
+
+        require!(
+            self.has_item(&equippable_token_id, &new_item) == false,
+            ERR_CANNOT_OVERRIDE_REGISTERED_ITEM
+        );
+
+        let is_insert_successful =
+            self.insert_item_token(&equippable_token_id, &new_item, &new_token);
+        require!(is_insert_successful, ERR_CANNOT_OVERRIDE_REGISTERED_ITEM);
+
+        self.supply_mode(&equippable_token_id, &new_item)
+            .set(supply_mode);
+        if is_consumable {
+            self.consumable_items(&equippable_token_id)
+                .insert(new_item.clone());
+        }
+        self.item_lock_duration(&equippable_token_id, &new_item)
+            .set(lock_duration);
+        for requirement in requirements.iter() {
+            require!(
+                requirement.slot != new_item.slot,
+                ERR_ITEM_CANNOT_REQUIRE_ITS_OWN_SLOT
+            );
+
+            self.item_requirements(&equippable_token_id, &new_item)
+                .insert(requirement);
+        }
+        self.rename_required_item(&equippable_token_id, &old_item, &new_item);
+
+        // renamed in the same slot: the equippables carrying the old item are migrated on customization.
+        // Moved to another slot, the old item stays unregistered so that it can still be unequipped.
+        if old_item != new_item && old_item.slot == new_item.slot {
+            self.remapped_items(&equippable_token_id, &old_item)
+                .set(&new_item);
+            self.clear_item_config(&equippable_token_id, &old_item);
+        }
+
+        self.registry_changes().push(&RegistryChange {
+            equippable_token_id,
+            kind: RegistryChangeKind::Remap,
+            old_item,
+            old_token: opt_old_token.unwrap(),
+            new_item: Option::Some(new_item),
+            new_token: Option::Some(new_token),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+    }
+
+    /// Set the token sent back to the users when they unequip the item.
+    #[endpoint(setPreferredToken)]
+    #[only_owner]
+    fn set_preferred_token(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        token_id: TokenIdentifier,
+        token_nonce: u64,
+    ) {
+        let item = Item { name, slot };
+        let token = Token::new(token_id, token_nonce);
+
+        require!(
+            self.tokens_of_item(&equippable_token_id, &item)
+                .contains(&token),
+            ERR_TOKEN_DOES_NOT_REPRESENT_ITEM
+        );
+
+        self.preferred_token_of_item(&equippable_token_id, &item)
+            .set(&token);
+    }
+
+    /// In mint mode, the contract burns the item tokens received and mints them back on unequip.
+    /// It requires the NftAddQuantity and NftBurn roles on every token of the item.
+    #[endpoint(setItemSupplyMode)]
+    #[only_owner]
+    fn set_item_supply_mode(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        supply_mode: SupplyMode,
+    ) {
+        let item = Item { name, slot };
+
+        require!(
+            self.has_item(&equippable_token_id, &item),
+            ERR_ITEM_NOT_REGISTERED
+        );
+
+        if supply_mode == SupplyMode::Mint {
+            for token in self.tokens_of_item(&equippable_token_id, &item).iter() {
+                self.require_item_roles_set(&token.token);
+            }
+        }
+
+        self.supply_mode(&equippable_token_id, &item)
+            .set(supply_mode);
+    }
+
+    #[view(getItemSupplyMode)]
+    fn get_item_supply_mode(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+    ) -> SupplyMode {
+        return self
+            .supply_mode(&equippable_token_id, &Item { name, slot })
+            .get();
+    }
+
+    /// Consumable items are burned on equip, and unequipping them sends nothing back.
+    /// It requires the NftBurn role on every token of the item.
+    #[endpoint(setItemConsumable)]
+    #[only_owner]
+    fn set_item_consumable(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        is_consumable: bool,
+    ) {
+        let item = Item { name, slot };
+
+        require!(
+            self.has_item(&equippable_token_id, &item),
+            ERR_ITEM_NOT_REGISTERED
+        );
+
+        if is_consumable {
+            for token in self.tokens_of_item(&equippable_token_id, &item).iter() {
+                self.require_item_burn_role_set(&token.token);
+            }
+
+            self.consumable_items(&equippable_token_id).insert(item);
+        } else {
+            self.consumable_items(&equippable_token_id)
+                .swap_remove(&item);
+        }
+    }
+
+    /// Once equipped, the item cannot be unequipped before `duration` seconds.
+    /// Pass PERMANENT_LOCK (u64::MAX) to never allow it, or zero to remove the lock.
+    #[endpoint(setItemLockDuration)]
+    #[only_owner]
+    fn set_item_lock_duration(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        duration: u64,
+    ) {
+        let item = Item { name, slot };
+
+        require!(
+            self.has_item(&equippable_token_id, &item),
+            ERR_ITEM_NOT_REGISTERED
+        );
+
+        self.item_lock_duration(&equippable_token_id, &item)
+            .set(duration);
+    }
+
+    /// Returns the timestamp from which the slot of the equippable can be unequipped.
+    /// Zero means that it is not locked, and PERMANENT_LOCK that it never will be.
+    #[view(getSlotUnlockTime)]
+    fn get_slot_unlock_time(
+        &self,
+        owner: ManagedAddress,
+        equippable_token_id: TokenIdentifier,
+        equippable_nonce: u64,
+        slot: ManagedBuffer,
+    ) -> u64 {
+        let attributes = self
+            .blockchain()
+            .get_esdt_token_data(&owner, &equippable_token_id, equippable_nonce)
+            .decode_attributes::<EquippableAttributes<Self::Api>>();
+
+        let unlock_timestamp = attributes.get_unlock_timestamp(&slot);
+
+        if unlock_timestamp <= self.blockchain().get_block_timestamp() {
+            return 0;
+        } else {
+            return unlock_timestamp;
+        }
+    }
+
+    /// Deposit item tokens. Can be called by the owner, or by a supplier for the tokens assigned to it.
     #[payable("*")]
     #[endpoint]
-    #[only_owner]
     fn fill(&self) {
         let payments = self.call_value().all_esdt_transfers();
+        let caller = self.blockchain().get_caller();
 
         for payment in &payments {
+            let token = Token::new(payment.token_identifier, payment.token_nonce);
+
+            self.require_can_fill(&caller, &token);
             require!(
-                self.has_token(&Token::new(payment.token_identifier, payment.token_nonce)),
+                self.is_item_token(&token),
                 ERR_CANNOT_FILL_UNREGISTERED_ITEM
-            )
+            );
+
+            self.record_deposit(&token, &payment.amount);
+            self.record_supply(&caller, &token, &payment.amount);
         }
     }
 
+    /// Send the EGLD balance to the owner, except the fee credits and revenues not withdrawn yet.
     #[only_owner]
     #[endpoint(claim)]
     fn claim(&self) {
         let balance = self
             .blockchain()
-            .get_balance(&self.blockchain().get_sc_address());
+            .get_balance(&self.blockchain().get_sc_address())
+            - self.fee_reserve(&EgldOrEsdtTokenIdentifier::egld()).get();
+
+        self.treasury(&EgldOrEsdtTokenIdentifier::egld()).clear();
+        self.treasury_tokens()
+            .swap_remove(&EgldOrEsdtTokenIdentifier::egld());
 
         self.send()
             .direct_egld(&self.blockchain().get_owner_address(), &balance, b"");
@@ -99,19 +517,56 @@ pub trait Equip:
     #[view(getItems)]
     fn get_items(
         &self,
+        equippable_token_id: TokenIdentifier,
     ) -> MultiValueEncoded<
-        MultiValue4<
+        MultiValue5<
             ManagedBuffer<Self::Api>,
             ManagedBuffer<Self::Api>,
             TokenIdentifier<Self::Api>,
             u64,
+            bool,
         >,
     > {
         let mut output = MultiValueEncoded::new();
 
-        for (item, token) in self.map_items_tokens().iter() {
-            let multi_value = MultiValue4::from((item.slot, item.name, token.token, token.nonce));
-            output.push(multi_value);
+        for item in self.registered_items(&equippable_token_id).iter() {
+            let is_consumable = self.consumable_items(&equippable_token_id).contains(&item);
+
+            for token in self.tokens_of_item(&equippable_token_id, &item).iter() {
+                let multi_value = MultiValue5::from((
+                    item.slot.clone(),
+                    item.name.clone(),
+                    token.token,
+                    token.nonce,
+                    is_consumable,
+                ));
+                output.push(multi_value);
+            }
+        }
+
+        return output;
+    }
+
+    #[view(getPreferredToken)]
+    fn get_preferred_token(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+    ) -> MultiValue2<TokenIdentifier, u64> {
+        let opt_token = self.get_token(&equippable_token_id, &Item { name, slot });
+        require!(opt_token.is_some(), ERR_ITEM_NOT_REGISTERED);
+
+        let token = opt_token.unwrap();
+        return MultiValue2::from((token.token, token.nonce));
+    }
+
+    #[view(getRegistryChanges)]
+    fn get_registry_changes(&self) -> MultiValueEncoded<RegistryChange<Self::Api>> {
+        let mut output = MultiValueEncoded::new();
+
+        for change in self.registry_changes().iter() {
+            output.push(change);
         }
 
         return output;
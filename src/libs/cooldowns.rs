@@ -0,0 +1,214 @@
+use crate::constants::*;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Minimum delay between two customizations of the same equippable, and of the same address.
+/// An equippable keeps its lineage when it is burned and minted again, so reminting does not reset its cooldown.
+#[elrond_wasm::module]
+pub trait CooldownsModule: super::storage::StorageModule {
+    /// In seconds.
+    #[storage_mapper("equippable_cooldown")]
+    fn equippable_cooldown(&self, equippable_token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// In seconds.
+    #[storage_mapper("address_cooldown")]
+    fn address_cooldown(&self, equippable_token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// The nonce of the first equippable of the lineage. Empty if the equippable has never been customized.
+    #[storage_mapper("lineage_of")]
+    fn lineage_of(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        equippable_nonce: u64,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_lineage_customization")]
+    fn last_lineage_customization(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        lineage: u64,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_address_customization")]
+    fn last_address_customization(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    /// Set the cooldowns of the collection, in seconds. Zero disables a cooldown.
+    #[endpoint(setCustomizationCooldowns)]
+    #[only_owner]
+    fn set_customization_cooldowns(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        equippable_cooldown: u64,
+        address_cooldown: u64,
+    ) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        self.equippable_cooldown(&equippable_token_id)
+            .set(equippable_cooldown);
+        self.address_cooldown(&equippable_token_id)
+            .set(address_cooldown);
+    }
+
+    /// Returns the equippable and the address cooldowns of the collection, in seconds.
+    #[view(getCustomizationCooldowns)]
+    fn get_customization_cooldowns(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValue2<u64, u64> {
+        return MultiValue2::from((
+            self.equippable_cooldown(&equippable_token_id).get(),
+            self.address_cooldown(&equippable_token_id).get(),
+        ));
+    }
+
+    /// Returns the timestamp from which the address can customize the equippable, or 0 if it can already.
+    #[view(getNextCustomizationTime)]
+    fn get_next_customization_time(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        equippable_nonce: u64,
+        address: ManagedAddress,
+    ) -> u64 {
+        let next_time = core::cmp::max(
+            self.get_next_equippable_customization_time(&equippable_token_id, equippable_nonce),
+            self.get_next_address_customization_time(&equippable_token_id, &address, false),
+        );
+
+        if next_time <= self.blockchain().get_block_timestamp() {
+            return 0;
+        } else {
+            return next_time;
+        }
+    }
+
+    fn get_lineage(&self, equippable_token_id: &TokenIdentifier, equippable_nonce: u64) -> u64 {
+        let lineage = self.lineage_of(equippable_token_id, equippable_nonce);
+
+        if lineage.is_empty() {
+            return equippable_nonce;
+        } else {
+            return lineage.get();
+        }
+    }
+
+    fn get_next_equippable_customization_time(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        equippable_nonce: u64,
+    ) -> u64 {
+        let lineage = self.get_lineage(equippable_token_id, equippable_nonce);
+        let last_customization = self
+            .last_lineage_customization(equippable_token_id, lineage)
+            .get();
+
+        if last_customization == 0 {
+            return 0;
+        }
+
+        return last_customization + self.equippable_cooldown(equippable_token_id).get();
+    }
+
+    /// `in_same_transaction` is true if the address already customized an equippable of the collection in this transaction.
+    /// Several equippables can then be customized at once; the address cooldown applies between transactions.
+    fn get_next_address_customization_time(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        address: &ManagedAddress,
+        in_same_transaction: bool,
+    ) -> u64 {
+        let last_customization = self
+            .last_address_customization(equippable_token_id, address)
+            .get();
+
+        if last_customization == 0
+            || (in_same_transaction
+                && last_customization == self.blockchain().get_block_timestamp())
+        {
+            return 0;
+        }
+
+        return last_customization + self.address_cooldown(equippable_token_id).get();
+    }
+
+    fn get_cooldown_error(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        equippable_nonce: u64,
+        address: &ManagedAddress,
+        in_same_transaction: bool,
+    ) -> Option<&'static str> {
+        let now = self.blockchain().get_block_timestamp();
+
+        if self.get_next_equippable_customization_time(equippable_token_id, equippable_nonce) > now
+        {
+            return Option::Some(ERR_EQUIPPABLE_COOLDOWN_NOT_ELAPSED);
+        }
+
+        if self.get_next_address_customization_time(
+            equippable_token_id,
+            address,
+            in_same_transaction,
+        ) > now
+        {
+            return Option::Some(ERR_ADDRESS_COOLDOWN_NOT_ELAPSED);
+        }
+
+        return Option::None;
+    }
+
+    fn require_cooldowns_elapsed(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        equippable_nonce: u64,
+        address: &ManagedAddress,
+        in_same_transaction: bool,
+    ) {
+        if let Some(error) = self.get_cooldown_error(
+            equippable_token_id,
+            equippable_nonce,
+            address,
+            in_same_transaction,
+        ) {
+            sc_panic!(error);
+        }
+    }
+
+    /// Returns the lineage, moved from the previous nonce to the new one.
+    fn move_lineage(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        previous_nonce: u64,
+        new_nonce: u64,
+    ) -> u64 {
+        let lineage = self.get_lineage(equippable_token_id, previous_nonce);
+
+        if new_nonce != previous_nonce {
+            self.lineage_of(equippable_token_id, previous_nonce).clear();
+            self.lineage_of(equippable_token_id, new_nonce).set(lineage);
+        }
+
+        return lineage;
+    }
+
+    /// Move the lineage to the new nonce, and start the cooldowns.
+    fn record_customization(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        previous_nonce: u64,
+        new_nonce: u64,
+        address: &ManagedAddress,
+    ) {
+        let now = self.blockchain().get_block_timestamp();
+        let lineage = self.move_lineage(equippable_token_id, previous_nonce, new_nonce);
+
+        self.last_lineage_customization(equippable_token_id, lineage)
+            .set(now);
+        self.last_address_customization(equippable_token_id, address)
+            .set(now);
+    }
+}
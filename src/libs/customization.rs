@@ -0,0 +1,451 @@
+use crate::{
+    constants::*,
+    structs::{
+        equippable_attributes::EquippableAttributes, item::Item, item_moves::ItemMoves,
+        supply_mode::SupplyMode, token::Token, update_mode::UpdateMode,
+    },
+};
+use core::ops::Deref;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Move the items of a customization between the user and the smart contract, and write the attributes to the equippable.
+/// The endpoints customizing the equippables are in CustomizeModule.
+#[elrond_wasm::module]
+pub trait CustomizationModule:
+    super::storage::StorageModule
+    + super::uris::UrisModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+{
+    /// Unequip the slots and equip the items, sending the unequipped items to the receiver.
+    /// Returns the previous and the new attributes; the equippable itself is left untouched.
+    fn apply_customization(
+        &self,
+        equippable_payment: &EsdtTokenPayment<Self::Api>,
+        items_payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+        to_unequip_slots: &ManagedVec<ManagedBuffer<Self::Api>>,
+        receiver: &ManagedAddress,
+    ) -> (
+        EquippableAttributes<Self::Api>,
+        EquippableAttributes<Self::Api>,
+    ) {
+        let (previous_attributes, attributes, moves) =
+            self.move_items(equippable_payment, items_payments, to_unequip_slots);
+
+        self.complete_item_moves(&moves, receiver);
+
+        return (previous_attributes, attributes);
+    }
+
+    /// Unequip the slots and equip the items, recording the tokens paid out of the stock.
+    /// The tokens received join the stock, and the tokens are minted and burned, only in `complete_item_moves`, so that a customization held in escrow can still be refunded.
+    /// Returns the previous and the new attributes, and the tokens moved.
+    fn move_items(
+        &self,
+        equippable_payment: &EsdtTokenPayment<Self::Api>,
+        items_payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+        to_unequip_slots: &ManagedVec<ManagedBuffer<Self::Api>>,
+    ) -> (
+        EquippableAttributes<Self::Api>,
+        EquippableAttributes<Self::Api>,
+        ItemMoves<Self::Api>,
+    ) {
+        require!(
+            items_payments.len() >= 1 || to_unequip_slots.len() >= 1,
+            ERR_NEED_ONE_ITEM_OR_UNEQUIP_SLOT
+        );
+
+        let equippable_token_id = equippable_payment.token_identifier.clone();
+        let equippable_nonce = equippable_payment.token_nonce;
+
+        require!(
+            self.equippable_token_ids().contains(&equippable_token_id),
+            ERR_FIRST_PAYMENT_IS_EQUIPPABLE
+        );
+
+        self.require_equippable_collection_roles_set(&equippable_token_id);
+
+        require!(
+            equippable_payment.amount == BigUint::from(1u64),
+            ERR_MORE_THAN_ONE_EQUIPPABLE_RECEIVED
+        );
+
+        let mut attributes = self.get_received_attributes(&equippable_token_id, equippable_nonce);
+        let mut moves = ItemMoves::default();
+
+        let previous_attributes = attributes.clone();
+
+        // first unequip
+        for slot in to_unequip_slots.iter() {
+            self.unequip_slot(&equippable_token_id, &mut attributes, &slot, &mut moves);
+        }
+
+        // then, equip
+        for payment in items_payments.iter() {
+            require!(
+                payment.amount == BigUint::from(1u64),
+                ERR_MORE_THAN_ONE_ITEM_RECEIVED
+            );
+
+            let token = Token::new(payment.token_identifier.clone(), payment.token_nonce);
+            let item = match self.get_item_to_equip(&equippable_token_id, &token) {
+                Result::Ok(item) => item,
+                Result::Err(error) => sc_panic!(error),
+            };
+
+            self.equip_slot(&equippable_token_id, &mut attributes, &item, &mut moves);
+            self.receive_item(&equippable_token_id, &item, &token, &mut moves);
+        }
+
+        self.require_customized_attributes_match_slots(
+            &equippable_token_id,
+            &previous_attributes,
+            &attributes,
+        );
+        self.require_items_requirements_met(
+            &equippable_token_id,
+            &previous_attributes,
+            &attributes,
+        );
+
+        return (previous_attributes, attributes, moves);
+    }
+
+    /// Record the tokens kept in custody, mint and burn the tokens listed by `move_items`, then send the unequipped tokens to the receiver.
+    fn complete_item_moves(&self, moves: &ItemMoves<Self::Api>, receiver: &ManagedAddress) {
+        for token in moves.equipped.iter() {
+            self.record_equip(&token);
+        }
+
+        for token in moves.to_mint.iter() {
+            self.require_item_roles_set(&token.token);
+
+            self.send()
+                .esdt_local_mint(&token.token, token.nonce, &BigUint::from(1u32));
+        }
+
+        for token in moves.to_burn.iter() {
+            self.send()
+                .esdt_local_burn(&token.token, token.nonce, &BigUint::from(1u32));
+        }
+
+        for token in moves
+            .paid_out
+            .iter()
+            .chain(moves.to_mint.iter())
+            .chain(moves.returned.iter())
+        {
+            self.send().direct_esdt(
+                receiver,
+                &token.token,
+                token.nonce,
+                &BigUint::from(1u32),
+                &[],
+            );
+        }
+    }
+
+    /// Returns the error `complete_item_moves` would fail with, e.g. if a role has been removed since the items were moved.
+    fn get_item_moves_error(&self, moves: &ItemMoves<Self::Api>) -> Option<&'static str> {
+        for token in moves.to_mint.iter() {
+            if let Some(error) = self.get_item_roles_error(&token.token) {
+                return Option::Some(error);
+            }
+        }
+
+        for token in moves.to_burn.iter() {
+            if let Some(error) = self.get_item_burn_role_error(&token.token) {
+                return Option::Some(error);
+            }
+        }
+
+        return Option::None;
+    }
+
+    /// Undo the records of `move_items`: the tokens paid out are back in the stock, and the items they released are equipped again.
+    fn revert_item_moves(&self, moves: &ItemMoves<Self::Api>) {
+        for token in moves.paid_out.iter() {
+            self.cancel_payout(&token);
+        }
+
+        for token in moves.released.iter() {
+            self.cancel_release(&token);
+        }
+    }
+
+    fn equip_slot(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &mut EquippableAttributes<Self::Api>,
+        item: &Item<Self::Api>,
+        moves: &mut ItemMoves<Self::Api>,
+    ) {
+        for slot in self
+            .get_slots_to_clear(equippable_token_id, attributes, item)
+            .iter()
+        {
+            self.unequip_slot(equippable_token_id, attributes, &slot, moves);
+        }
+
+        attributes.set_item(&item.slot, Option::Some(item.name.clone()));
+
+        self.lock_equipped_item(equippable_token_id, attributes, item);
+    }
+
+    /// Empty the item at the slot provided, and take its token out of the stock, or list it to mint in mint mode.
+    /// A token received earlier in the customization is sent back instead of a token of the stock.
+    /// If the slot is mandatory, its default item is equipped instead.
+    fn unequip_slot(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &mut EquippableAttributes<Self::Api>,
+        slot: &ManagedBuffer<Self::Api>,
+        moves: &mut ItemMoves<Self::Api>,
+    ) {
+        let (item, opt_token) = match self.preview_unequip_slot(
+            equippable_token_id,
+            attributes,
+            slot,
+            &mut ManagedVec::new(),
+            &moves.equipped,
+        ) {
+            Result::Ok(unequipped) => unequipped,
+            Result::Err(error) => sc_panic!(error),
+        };
+
+        self.start_inventory_ledger();
+
+        if let Some(token) = opt_token {
+            match self.supply_mode(equippable_token_id, &item).get() {
+                SupplyMode::Custody => {
+                    match moves.equipped.iter().position(|received| received == token) {
+                        Option::Some(index) => {
+                            moves.equipped.remove(index);
+                            moves.returned.push(token);
+                        }
+                        Option::None => {
+                            if let Some(released) =
+                                self.record_payout(equippable_token_id, &item, &token)
+                            {
+                                moves.released.push(released);
+                            }
+                            moves.paid_out.push(token);
+                        }
+                    }
+                }
+                SupplyMode::Mint => moves.to_mint.push(token),
+            }
+        }
+    }
+
+    /// List the token received to keep in custody, or to burn in mint mode or if the item is consumable.
+    fn receive_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        token: &Token<Self::Api>,
+        moves: &mut ItemMoves<Self::Api>,
+    ) {
+        if let Some(error) = self.get_receive_item_error(equippable_token_id, item, token) {
+            sc_panic!(error);
+        }
+
+        self.start_inventory_ledger();
+
+        if self.is_item_kept_in_custody(equippable_token_id, item) {
+            moves.equipped.push(token.clone());
+        } else {
+            moves.to_burn.push(token.clone());
+        }
+    }
+
+    /// Give the attributes to the equippable, either in place or by reminting it, and send it to the receiver.
+    fn write_equippable(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        input_nonce: u64,
+        attributes: &EquippableAttributes<Self::Api>,
+        receiver: &ManagedAddress,
+    ) -> u64 {
+        let esdt_data = self.blockchain().get_esdt_token_data(
+            &self.blockchain().get_sc_address(),
+            equippable_token_id,
+            input_nonce,
+        );
+
+        self.placeholder_nonces(equippable_token_id)
+            .swap_remove(&input_nonce);
+
+        let new_nonce = if self.update_mode(equippable_token_id).get() == UpdateMode::InPlace {
+            self.update_equippable_in_place(
+                equippable_token_id,
+                input_nonce,
+                attributes,
+                &esdt_data.name,
+                &esdt_data.uris,
+            );
+
+            input_nonce
+        } else {
+            self.remint_equippable(equippable_token_id, input_nonce, attributes, &esdt_data)
+        };
+
+        if self
+            .find_uri(
+                equippable_token_id,
+                &attributes.without_locks(),
+                &esdt_data.name,
+            )
+            .is_none()
+        {
+            self.placeholder_nonces(equippable_token_id)
+                .insert(new_nonce);
+        }
+
+        self.send().direct_esdt(
+            receiver,
+            equippable_token_id,
+            new_nonce,
+            &BigUint::from(1u32),
+            &[],
+        );
+
+        return new_nonce;
+    }
+
+    /// The generated URIs not added yet are added, then the image, so the last URI is always the current image.
+    fn update_equippable_in_place(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        nonce: u64,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer,
+        previous_uris: &ManagedVec<ManagedBuffer<Self::Api>>,
+    ) {
+        let mut uris = ManagedVec::new();
+        for generated_uri in self
+            .build_generated_uris(equippable_token_id, &self.get_attributes_hash(attributes))
+            .iter()
+        {
+            if previous_uris.contains(&generated_uri) == false {
+                uris.push(generated_uri.deref().clone());
+            }
+        }
+        uris.push(self.get_thumbnail_uri(equippable_token_id, attributes, name));
+
+        self.send()
+            .nft_update_attributes(equippable_token_id, nonce, attributes);
+        self.send()
+            .nft_add_multiple_uri(equippable_token_id, nonce, &uris);
+    }
+
+    /// Burn the equippable and mint a new one with the attributes. Returns the new nonce.
+    fn remint_equippable(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        input_nonce: u64,
+        attributes: &EquippableAttributes<Self::Api>,
+        esdt_data: &EsdtTokenData<Self::Api>,
+    ) -> u64 {
+        // mint a new one
+        let royalties = if self.royalties_overrided().is_empty() {
+            esdt_data.royalties.clone()
+        } else {
+            self.royalties_overrided().get()
+        };
+        let minted_nonce = self.mint_equippable(
+            equippable_token_id,
+            &attributes,
+            &esdt_data.name,
+            &royalties,
+            &esdt_data.decode_attributes::<EquippableAttributes<Self::Api>>(),
+            &esdt_data.uris,
+        );
+
+        // burn the old one
+        self.send()
+            .esdt_local_burn(equippable_token_id, input_nonce, &BigUint::from(1u32));
+
+        return minted_nonce;
+    }
+
+    /// Mint the equippable with the image of the attributes as first URI, then the generated URIs.
+    /// The other URIs of the previous equippable (e.g. a license) are carried over; its image is not.
+    fn mint_equippable(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer,
+        royalties: &BigUint<Self::Api>,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        previous_uris: &ManagedVec<ManagedBuffer<Self::Api>>,
+    ) -> u64 {
+        let hash = self.get_attributes_hash(attributes);
+        let uris = self.build_equippable_uris(
+            equippable_token_id,
+            attributes,
+            name,
+            &hash,
+            previous_attributes,
+            previous_uris,
+        );
+
+        let token_nonce = self
+            .send()
+            .esdt_nft_create::<EquippableAttributes<Self::Api>>(
+                equippable_token_id,
+                &BigUint::from(1u32),
+                name,
+                royalties,
+                &hash,
+                attributes,
+                &uris,
+            );
+
+        return token_nonce;
+    }
+
+    /// The thumbnail first, then the generated URIs, then the URIs of the previous Equippable.
+    /// The previous URIs that are generated ones, or the image of the previous attributes or the placeholder, are dropped.
+    fn build_equippable_uris(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer,
+        hash: &ManagedBuffer,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        previous_uris: &ManagedVec<ManagedBuffer<Self::Api>>,
+    ) -> ManagedVec<ManagedBuffer<Self::Api>> {
+        let mut uris = ManagedVec::new();
+        let thumbnail = self.get_thumbnail_uri(equippable_token_id, attributes, name);
+        uris.push(thumbnail.clone());
+        uris.append_vec(self.build_generated_uris(equippable_token_id, hash));
+
+        let previous_thumbnail = self.find_uri(
+            equippable_token_id,
+            &previous_attributes.without_locks(),
+            name,
+        );
+        let placeholder = self.placeholder_uri(equippable_token_id).get();
+
+        for uri in previous_uris.iter() {
+            let is_previous_image = previous_thumbnail.as_ref() == Option::Some(uri.deref())
+                || (placeholder.is_empty() == false && *uri == placeholder);
+
+            if is_previous_image == false
+                && *uri != thumbnail
+                && self.is_generated_uri(equippable_token_id, &uri) == false
+            {
+                uris.push(uri.deref().clone());
+            }
+        }
+
+        return uris;
+    }
+}
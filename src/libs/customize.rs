@@ -1,222 +1,344 @@
-use crate::{
-    constants::*,
-    structs::{equippable_attributes::EquippableAttributes, item::Item, token::Token},
-};
+use crate::{constants::*, structs::equippable_attributes::EquippableAttributes};
 
 elrond_wasm::imports!();
 elrond_wasm::derive_imports!();
 
 #[elrond_wasm::module]
 pub trait CustomizeModule:
-    super::storage::StorageModule + super::equippable_uris::EquippableUrisModule
+    super::storage::StorageModule
+    + super::uris::UrisModule
+    + super::treasury::TreasuryModule
+    + super::pause::PauseModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+    + super::customization::CustomizationModule
+    + super::escrow::EscrowModule
 {
+    /// Returns the new nonce of the equippable, or 0 if the customization is held in escrow until its image is rendered.
     #[payable("*")]
     #[endpoint(customize)]
     fn customize(&self, to_unequip_slots: MultiValueEncoded<ManagedBuffer<Self::Api>>) -> u64 {
+        self.require_not_paused(b"customize");
+
         let payments = self.call_value().all_esdt_transfers();
 
-        self.require_equippable_collection_roles_set();
         require!(payments.len() >= 1, ERR_NEED_EQUIPPABLE);
+
+        let equippable_payment = payments.get(0);
+        let items_payments = payments.slice(1, payments.len()).unwrap_or_default();
+        let to_unequip_slots = to_unequip_slots.to_vec();
+
+        if self.hold_if_not_rendered(&equippable_payment, &items_payments, &to_unequip_slots) {
+            return 0;
+        }
+
+        return self.customize_equippable(
+            &equippable_payment,
+            &items_payments,
+            &to_unequip_slots,
+            &self.blockchain().get_caller(),
+            false,
+        );
+    }
+
+    /// Customize several equippables at once.
+    /// Each equippable sent is followed by its items to equip; each instruction gives the number of items following the equippable, and the slots to unequip.
+    /// Returns the new nonces, in the same order as the equippables sent.
+    #[payable("*")]
+    #[endpoint(customizeBatch)]
+    fn customize_batch(
+        &self,
+        instructions: MultiValueEncoded<MultiValue2<u32, ManagedVec<ManagedBuffer<Self::Api>>>>,
+    ) -> MultiValueEncoded<u64> {
+        self.require_not_paused(b"customizeBatch");
+
+        let payments = self.call_value().all_esdt_transfers();
+
+        require!(instructions.len() >= 1, ERR_NEED_ONE_BATCH_INSTRUCTION);
+
+        let caller = self.blockchain().get_caller();
+        let mut new_nonces = MultiValueEncoded::new();
+        let mut customized_collections = ManagedVec::<Self::Api, TokenIdentifier>::new();
+        let mut index = 0;
+
+        for instruction in instructions.into_iter() {
+            let (items_count, to_unequip_slots) = instruction.into_tuple();
+            let items_end = index + 1 + items_count as usize;
+
+            require!(
+                items_end <= payments.len(),
+                ERR_BATCH_PAYMENTS_DO_NOT_MATCH_INSTRUCTIONS
+            );
+
+            let equippable_payment = payments.get(index);
+            let items_payments = payments.slice(index + 1, items_end).unwrap_or_default();
+            let new_nonce = self.customize_equippable(
+                &equippable_payment,
+                &items_payments,
+                &to_unequip_slots,
+                &caller,
+                customized_collections.contains(&equippable_payment.token_identifier),
+            );
+            new_nonces.push(new_nonce);
+            customized_collections.push(equippable_payment.token_identifier);
+
+            index = items_end;
+        }
+
         require!(
-            payments.len() >= 2 || to_unequip_slots.len() >= 1,
-            ERR_NEED_ONE_ITEM_OR_UNEQUIP_SLOT
+            index == payments.len(),
+            ERR_BATCH_PAYMENTS_DO_NOT_MATCH_INSTRUCTIONS
+        );
+
+        return new_nonces;
+    }
+
+    /// The customized equippable and the unequipped items are sent to the receiver.
+    fn customize_equippable(
+        &self,
+        equippable_payment: &EsdtTokenPayment<Self::Api>,
+        items_payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+        to_unequip_slots: &ManagedVec<ManagedBuffer<Self::Api>>,
+        receiver: &ManagedAddress,
+        in_same_transaction: bool,
+    ) -> u64 {
+        let equippable_token_id = &equippable_payment.token_identifier;
+
+        let (previous_attributes, attributes) = self.apply_customization(
+            equippable_payment,
+            items_payments,
+            to_unequip_slots,
+            receiver,
+        );
+
+        self.charge_customization_fee(
+            &self.blockchain().get_caller(),
+            equippable_token_id,
+            &previous_attributes,
+            &attributes,
+            items_payments.len(),
         );
 
+        return self.update_equippable(
+            equippable_token_id,
+            equippable_payment.token_nonce,
+            &attributes,
+            receiver,
+            in_same_transaction,
+        );
+    }
+
+    /// Move the items of the slots provided between two equippables of the caller, and send both back.
+    /// Returns the new nonces of the first and second equippable.
+    #[payable("*")]
+    #[endpoint(swap)]
+    fn swap(&self, slots: MultiValueEncoded<ManagedBuffer<Self::Api>>) -> MultiValue2<u64, u64> {
+        self.require_not_paused(b"swap");
+
+        let payments = self.call_value().all_esdt_transfers();
+
+        require!(payments.len() == 2, ERR_SWAP_NEEDS_TWO_EQUIPPABLES);
+        require!(slots.len() >= 1, ERR_NEED_ONE_SLOT_TO_SWAP);
+
         let first_payment = payments.get(0);
+        let second_payment = payments.get(1);
         let equippable_token_id = first_payment.token_identifier;
-        let equippable_nonce = first_payment.token_nonce;
 
         require!(
-            &equippable_token_id == &self.equippable_token_id().get(),
+            self.equippable_token_ids().contains(&equippable_token_id),
             ERR_FIRST_PAYMENT_IS_EQUIPPABLE
         );
-
         require!(
-            first_payment.amount == BigUint::from(1u64),
+            second_payment.token_identifier == equippable_token_id
+                && second_payment.token_nonce != first_payment.token_nonce,
+            ERR_SWAP_NEEDS_TWO_EQUIPPABLES
+        );
+        require!(
+            first_payment.amount == BigUint::from(1u64)
+                && second_payment.amount == BigUint::from(1u64),
             ERR_MORE_THAN_ONE_EQUIPPABLE_RECEIVED
         );
 
-        let mut attributes = self
-            .blockchain()
-            .get_esdt_token_data(
-                &self.blockchain().get_sc_address(),
-                &equippable_token_id,
-                equippable_nonce,
-            )
-            .decode_attributes::<EquippableAttributes<Self::Api>>();
+        self.require_equippable_collection_roles_set(&equippable_token_id);
 
-        // first unequip
-        for slot in to_unequip_slots {
-            self.unequip_slot(&mut attributes, &slot);
-        }
+        let mut first_attributes =
+            self.get_received_attributes(&equippable_token_id, first_payment.token_nonce);
+        let mut second_attributes =
+            self.get_received_attributes(&equippable_token_id, second_payment.token_nonce);
+
+        let first_previous_attributes = first_attributes.clone();
+        let second_previous_attributes = second_attributes.clone();
+
+        for slot in slots.into_iter() {
+            let first_name = first_attributes.get_name(&slot);
+            let second_name = second_attributes.get_name(&slot);
 
-        // then, equip
-        let items_token = payments.iter().skip(1);
-        for payment in items_token {
             require!(
-                payment.amount == BigUint::from(1u64),
-                ERR_MORE_THAN_ONE_ITEM_RECEIVED
+                first_name.is_some() || second_name.is_some(),
+                ERR_CANNOT_SWAP_EMPTY_SLOTS
             );
 
-            let token = Token::new(payment.token_identifier.clone(), payment.token_nonce);
-
-            let printable_token_identifier = payment.token_identifier.as_managed_buffer();
-            let printable_token_nonce = payment.token_nonce;
+            let now = self.blockchain().get_block_timestamp();
             require!(
-                self.has_token(&token),
-                "The item you are equipping {} {} is not registered.",
-                printable_token_identifier,
-                printable_token_nonce
+                first_attributes.get_unlock_timestamp(&slot) <= now
+                    && second_attributes.get_unlock_timestamp(&slot) <= now,
+                "The slot {} is locked.",
+                slot
             );
 
-            self.equip_slot(&mut attributes, &self.map_items_tokens().get_id(&token));
+            first_attributes.set_item(&slot, second_name);
+            second_attributes.set_item(&slot, first_name);
         }
 
-        return self.update_equippable(equippable_nonce, &attributes);
-    }
-
-    fn equip_slot(&self, attributes: &mut EquippableAttributes<Self::Api>, item: &Item<Self::Api>) {
-        // unequip slot if any
-        if attributes.is_slot_empty(&item.slot) == false {
-            self.unequip_slot(attributes, &item.slot);
+        for (previous_attributes, attributes) in [
+            (&first_previous_attributes, &first_attributes),
+            (&second_previous_attributes, &second_attributes),
+        ] {
+            self.require_customized_attributes_match_slots(
+                &equippable_token_id,
+                previous_attributes,
+                attributes,
+            );
+            self.require_no_conflicting_slots_equipped(&equippable_token_id, attributes);
         }
 
-        attributes.set_item_if_empty(&item.slot, Option::Some(item.name.clone()));
+        self.require_items_requirements_met(
+            &equippable_token_id,
+            &first_previous_attributes,
+            &first_attributes,
+        );
+        self.require_items_requirements_met(
+            &equippable_token_id,
+            &second_previous_attributes,
+            &second_attributes,
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.charge_customization_fee(
+            &caller,
+            &equippable_token_id,
+            &first_previous_attributes,
+            &first_attributes,
+            0,
+        );
+        self.charge_customization_fee(
+            &caller,
+            &equippable_token_id,
+            &second_previous_attributes,
+            &second_attributes,
+            0,
+        );
+
+        let first_nonce = self.update_equippable(
+            &equippable_token_id,
+            first_payment.token_nonce,
+            &first_attributes,
+            &caller,
+            false,
+        );
+        let second_nonce = self.update_equippable(
+            &equippable_token_id,
+            second_payment.token_nonce,
+            &second_attributes,
+            &caller,
+            true,
+        );
+
+        return MultiValue2::from((first_nonce, second_nonce));
     }
 
-    /// Empty the item at the slot provided and sent it to the caller.
-    fn unequip_slot(
+    fn require_no_conflicting_slots_equipped(
         &self,
-        attributes: &mut EquippableAttributes<Self::Api>,
-        slot: &ManagedBuffer<Self::Api>,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
     ) {
-        let opt_name = attributes.get_name(&slot);
-
-        match opt_name {
-            Some(name) => {
-                let item = Item {
-                    slot: slot.clone(),
-                    name,
-                };
-
-                match self.get_token(&item) {
-                    Some(token) => {
-                        let item_id = token.token;
-                        let item_nonce = token.nonce;
-
-                        require!(
-                            self.blockchain().get_sc_balance(
-                                &EgldOrEsdtTokenIdentifier::esdt(item_id.clone()),
-                                item_nonce
-                            ) > 0,
-                            "Can't send {}-{:x} items to the user. There is no SFT remaining.",
-                            item_id,
-                            item_nonce
-                        );
-
-                        self.send().direct_esdt(
-                            &self.blockchain().get_caller(),
-                            &item_id,
-                            item_nonce,
-                            &BigUint::from(1u32),
-                            &[],
-                        );
-
-                        attributes.empty_slot(&slot);
-                    }
-
-                    None => {
-                        sc_panic!(
-                            "The item you are unequipping at slot {} is not registered.",
-                            slot
-                        );
-                    }
-                }
-            }
-
-            None => {
-                sc_panic!(ERR_CANNOT_UNEQUIP_EMPTY_SLOT);
+        for item in attributes.get_equipped_items().iter() {
+            for conflicting_slot in self
+                .get_conflicting_slots(equippable_token_id, &item.slot)
+                .iter()
+            {
+                require!(
+                    attributes.is_slot_empty(&conflicting_slot),
+                    ERR_CONFLICTING_SLOTS_EQUIPPED
+                );
             }
         }
     }
 
-    /// Make sure that the smart contract can create and burn the equippable.
-    fn require_equippable_collection_roles_set(&self) {
-        let roles = self
-            .blockchain()
-            .get_esdt_local_roles(&self.equippable_token_id().get());
-
-        require!(
-            roles.has_role(&EsdtLocalRole::NftCreate) == true,
-            ERR_CREATE_ROLE_NOT_SET_FOR_EQUIPPABLE
-        );
-
-        require!(
-            roles.has_role(&EsdtLocalRole::NftBurn) == true,
-            ERR_BURN_ROLE_NOT_SET_FOR_EQUIPPABLE
-        );
-    }
-
     fn update_equippable(
         &self,
+        equippable_token_id: &TokenIdentifier,
         input_nonce: u64,
         attributes: &EquippableAttributes<Self::Api>,
+        receiver: &ManagedAddress,
+        in_same_transaction: bool,
     ) -> u64 {
-        let equippable_token_id = self.equippable_token_id().get();
-        let caller = self.blockchain().get_caller();
+        self.require_cooldowns_elapsed(
+            equippable_token_id,
+            input_nonce,
+            receiver,
+            in_same_transaction,
+        );
+
+        let new_nonce =
+            self.write_equippable(equippable_token_id, input_nonce, attributes, receiver);
+
+        self.record_customization(equippable_token_id, input_nonce, new_nonce, receiver);
+
+        return new_nonce;
+    }
+
+    /// Replace the placeholder image of the equippable sent by its rendered image, and send it back.
+    /// Setting the URI of the attributes does not replace the placeholder: the holder has to send the equippable here.
+    /// Returns the nonce of the equippable, which changes unless it is updated in place.
+    #[payable("*")]
+    #[endpoint(refreshUri)]
+    fn refresh_uri(&self) -> u64 {
+        self.require_not_paused(b"refreshUri");
+
+        let payment = self.call_value().single_esdt();
+        let equippable_token_id = payment.token_identifier;
+
+        require!(
+            self.equippable_token_ids().contains(&equippable_token_id),
+            ERR_FIRST_PAYMENT_IS_EQUIPPABLE
+        );
+        require!(
+            self.placeholder_nonces(&equippable_token_id)
+                .contains(&payment.token_nonce),
+            ERR_NO_PLACEHOLDER_TO_REPLACE
+        );
 
         let esdt_data = self.blockchain().get_esdt_token_data(
             &self.blockchain().get_sc_address(),
             &equippable_token_id,
-            input_nonce,
+            payment.token_nonce,
         );
+        let attributes = esdt_data.decode_attributes::<EquippableAttributes<Self::Api>>();
 
-        // mint a new one
-        let royalties = if self.royalties_overrided().is_empty() {
-            esdt_data.royalties
-        } else {
-            self.royalties_overrided().get()
-        };
-        let minted_nonce = self.mint_equippable(&attributes, &esdt_data.name, &royalties);
-
-        // burn the old one
-        self.send()
-            .esdt_local_burn(&equippable_token_id, input_nonce, &BigUint::from(1u32));
+        require!(
+            self.find_uri(
+                &equippable_token_id,
+                &attributes.without_locks(),
+                &esdt_data.name
+            )
+            .is_some(),
+            ERR_IMAGE_NOT_RENDERED_YET
+        );
 
-        // send the new one
-        self.send().direct_esdt(
-            &caller,
+        let new_nonce = self.write_equippable(
             &equippable_token_id,
-            minted_nonce,
-            &BigUint::from(1u32),
-            &[],
+            payment.token_nonce,
+            &attributes,
+            &self.blockchain().get_caller(),
         );
 
-        return minted_nonce;
-    }
-
-    fn mint_equippable(
-        &self,
-        attributes: &EquippableAttributes<Self::Api>,
-        name: &ManagedBuffer,
-        royalties: &BigUint<Self::Api>,
-    ) -> u64 {
-        let mut uris = ManagedVec::new();
-        let thumbnail = self.get_uri_of(&attributes, name);
-        uris.push(thumbnail);
-
-        let token_nonce = self
-            .send()
-            .esdt_nft_create::<EquippableAttributes<Self::Api>>(
-                &self.equippable_token_id().get(),
-                &BigUint::from(1u32),
-                name,
-                royalties,
-                &ManagedBuffer::new(),
-                attributes,
-                &uris,
-            );
+        self.move_lineage(&equippable_token_id, payment.token_nonce, new_nonce);
 
-        return token_nonce;
+        return new_nonce;
     }
 }
@@ -1,4 +1,7 @@
-use crate::{constants::*, structs::equippable_attributes::EquippableAttributes};
+use crate::{
+    constants::*,
+    structs::{equippable_attributes::EquippableAttributes, generated_uri::GeneratedUri},
+};
 
 elrond_wasm::imports!();
 elrond_wasm::derive_imports!();
@@ -7,18 +10,119 @@ pub const ERR_ATTRIBUTES_MISMATCH: &str =
     "The attributes you are assigning do not match the attributes in the render queue.";
 
 #[elrond_wasm::module]
-pub trait EquippableUrisModule: super::storage::StorageModule {
-    #[storage_mapper("attributes_to_render_by_name")]
-    fn attributes_to_render_by_name(
+pub trait EquippableUrisModule:
+    super::storage::StorageModule
+    + super::slots::SlotsModule
+    + super::treasury::TreasuryModule
+    + super::pause::PauseModule
+    + super::uris::UrisModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+    + super::customization::CustomizationModule
+    + super::escrow::EscrowModule
+{
+    /// Add a URI, such as a metadata JSON, to every equippable of the collection customized from now.
+    /// The URI is `prefix + hash + suffix`, where hash is the hexadecimal SHA-256 of the attributes.
+    #[endpoint(addGeneratedUri)]
+    #[only_owner]
+    fn add_generated_uri(
         &self,
-    ) -> MapMapper<ManagedBuffer<Self::Api>, EquippableAttributes<Self::Api>>;
+        equippable_token_id: TokenIdentifier,
+        prefix: ManagedBuffer,
+        suffix: ManagedBuffer,
+    ) {
+        self.require_equippable_collection(&equippable_token_id);
+        require!(prefix.is_empty() == false, ERR_GENERATED_URI_PREFIX_EMPTY);
+
+        self.generated_uris(&equippable_token_id)
+            .insert(GeneratedUri { prefix, suffix });
+    }
 
-    #[storage_mapper("uris_of_attributes")]
-    fn uris_of_attributes(
+    #[endpoint(removeGeneratedUri)]
+    #[only_owner]
+    fn remove_generated_uri(
         &self,
-        attributes: &EquippableAttributes<Self::Api>,
-        name: &ManagedBuffer<Self::Api>,
-    ) -> SingleValueMapper<ManagedBuffer>;
+        equippable_token_id: TokenIdentifier,
+        prefix: ManagedBuffer,
+        suffix: ManagedBuffer,
+    ) {
+        require!(
+            self.generated_uris(&equippable_token_id)
+                .swap_remove(&GeneratedUri { prefix, suffix }),
+            ERR_GENERATED_URI_NOT_FOUND
+        );
+    }
+
+    #[view(getGeneratedUris)]
+    fn get_generated_uris(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>> {
+        let mut output = MultiValueEncoded::new();
+
+        for generated_uri in self.generated_uris(&equippable_token_id).iter() {
+            output.push(MultiValue2::from((
+                generated_uri.prefix,
+                generated_uri.suffix,
+            )));
+        }
+
+        return output;
+    }
+
+    /// An empty URI disables the placeholder.
+    #[endpoint(setPlaceholderUri)]
+    #[only_owner]
+    fn set_placeholder_uri(&self, equippable_token_id: TokenIdentifier, uri: ManagedBuffer) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        self.placeholder_uri(&equippable_token_id).set(uri);
+    }
+
+    #[view(getPlaceholderUri)]
+    fn get_placeholder_uri(&self, equippable_token_id: TokenIdentifier) -> ManagedBuffer {
+        return self.placeholder_uri(&equippable_token_id).get();
+    }
+
+    /// Returns the nonces of the equippables whose image is the placeholder.
+    #[view(getPlaceholderNonces)]
+    fn get_placeholder_nonces(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<u64> {
+        let mut output = MultiValueEncoded::new();
+
+        for nonce in self.placeholder_nonces(&equippable_token_id).iter() {
+            output.push(nonce);
+        }
+
+        return output;
+    }
+
+    /// Move the images waiting to be rendered before the upgrade into the render queue of the legacy collection.
+    #[endpoint(migrateLegacyRenderQueue)]
+    #[only_owner]
+    fn migrate_legacy_render_queue(&self) {
+        let equippable_token_id = self.legacy_equippable_token_id().get();
+        self.require_equippable_collection(&equippable_token_id);
+
+        for (name, attributes) in self.legacy_attributes_to_render_by_name().iter() {
+            let mut queue = self.attributes_to_render_by_name(&equippable_token_id);
+
+            if queue.contains_key(&name) == false
+                && self
+                    .find_uri(&equippable_token_id, &attributes, &name)
+                    .is_none()
+            {
+                queue.insert(name, attributes);
+            }
+        }
+
+        self.legacy_attributes_to_render_by_name().clear();
+    }
 
     #[endpoint(authorizeAddressToSetUris)]
     #[only_owner]
@@ -28,51 +132,89 @@ pub trait EquippableUrisModule: super::storage::StorageModule {
 
     /**
      * We could have used ImageToRender but we need to use the EquippableAttributes TopEncode.
+     * Payable in one of the tokens accepted by the owner.
      */
     #[endpoint(renderImage)]
-    #[payable("EGLD")]
+    #[payable("*")]
     fn enqueue_image_to_render(
         &self,
+        equippable_token_id: &TokenIdentifier,
         attributes: &EquippableAttributes<Self::Api>,
         name: &ManagedBuffer<Self::Api>,
     ) {
+        self.require_not_paused(b"renderImage");
+        self.receive_render_payment();
+
+        self.require_equippable_collection(equippable_token_id);
         require!(
-            self.call_value().egld_value() == BigUint::from(ENQUEUE_PRICE),
-            ERR_PAY_0001_EGLD
+            self.do_attributes_match_slots(equippable_token_id, attributes),
+            ERR_ATTRIBUTES_DO_NOT_MATCH_COLLECTION_SLOTS
         );
 
         require!(
-            self.uris_of_attributes(&attributes, &name).is_empty(),
+            self.find_uri(equippable_token_id, attributes, name)
+                .is_none(),
             ERR_CANNOT_ENQUEUE_IMAGE_BECAUSE_ALREADY_RENDERED
         );
         require!(
-            self.attributes_to_render_by_name().contains_key(&name) == false,
+            self.attributes_to_render_by_name(equippable_token_id)
+                .contains_key(name)
+                == false,
             ERR_RENDER_ALREADY_IN_QUEUE
         );
-        self.attributes_to_render_by_name()
+        self.attributes_to_render_by_name(equippable_token_id)
             .insert(name.clone(), attributes.clone());
     }
 
     #[view(getImagesToRender)]
     fn get_images_to_render(
         &self,
-    ) -> MultiValueEncoded<MultiValue2<EquippableAttributes<Self::Api>, ManagedBuffer>> {
+    ) -> MultiValueEncoded<
+        MultiValue3<TokenIdentifier, EquippableAttributes<Self::Api>, ManagedBuffer>,
+    > {
         let mut o = MultiValueEncoded::new();
 
-        for (name, attributes) in self.attributes_to_render_by_name().iter() {
-            o.push(MultiValue2::from((attributes, name)));
+        for equippable_token_id in self.equippable_token_ids().iter() {
+            for (name, attributes) in self
+                .attributes_to_render_by_name(&equippable_token_id)
+                .iter()
+            {
+                o.push(MultiValue3::from((
+                    equippable_token_id.clone(),
+                    attributes,
+                    name,
+                )));
+            }
         }
 
         return o;
     }
 
+    /// Set the URI of rendered attributes, and finalize the customizations waiting for them.
     #[endpoint(setUriOfAttributes)]
     fn set_uri_of_attributes(
         &self,
         uri_kvp: MultiValueEncoded<
-            MultiValue3<EquippableAttributes<Self::Api>, ManagedBuffer, ManagedBuffer<Self::Api>>,
+            MultiValue4<
+                TokenIdentifier,
+                EquippableAttributes<Self::Api>,
+                ManagedBuffer,
+                ManagedBuffer<Self::Api>,
+            >,
         >,
     ) {
+        self.require_not_paused(b"setUriOfAttributes");
+        self.require_can_set_uris();
+
+        for kvp in uri_kvp {
+            let (equippable_token_id, attributes, name, uri) = kvp.into_tuple();
+
+            self.set_uri_of(&equippable_token_id, &attributes, &name, uri);
+            self.finalize_pending_customizations(&equippable_token_id, &attributes, &name);
+        }
+    }
+
+    fn require_can_set_uris(&self) {
         let caller = &self.blockchain().get_caller();
 
         require!(
@@ -80,45 +222,40 @@ pub trait EquippableUrisModule: super::storage::StorageModule {
                 || self.authorized_addresses_to_set_uris().contains(caller) == true,
             "You don't have the permission to call this endpoint."
         );
-
-        for kvp in uri_kvp {
-            let (attributes, name, uri) = kvp.into_tuple();
-
-            require!(
-                self.uris_of_attributes(&attributes, &name).is_empty(),
-                ERR_CANNOT_OVERRIDE_URI_OF_ATTRIBUTE
-            );
-
-            require!(
-                self.attributes_to_render_by_name().contains_key(&name),
-                ERR_IMAGE_NOT_IN_RENDER_QUEUE
-            );
-
-            require!(
-                &self.attributes_to_render_by_name().get(&name).unwrap() == &attributes,
-                ERR_ATTRIBUTES_MISMATCH
-            );
-
-            self.uris_of_attributes(&attributes, &name).set(uri);
-            self.attributes_to_render_by_name().remove(&name);
-        }
     }
 
-    #[view(getUriOf)]
-    fn get_uri_of(
+    /// Set the URI of attributes waiting in the render queue.
+    fn set_uri_of(
         &self,
+        equippable_token_id: &TokenIdentifier,
         attributes: &EquippableAttributes<Self::Api>,
         name: &ManagedBuffer<Self::Api>,
-    ) -> ManagedBuffer<Self::Api> {
-        let uri = self.uris_of_attributes(attributes, name);
+        uri: ManagedBuffer<Self::Api>,
+    ) {
+        require!(
+            self.find_uri(equippable_token_id, attributes, name)
+                .is_none(),
+            ERR_CANNOT_OVERRIDE_URI_OF_ATTRIBUTE
+        );
+
+        let opt_queued_attributes = self
+            .attributes_to_render_by_name(equippable_token_id)
+            .get(name);
+
+        require!(
+            opt_queued_attributes.is_some(),
+            ERR_IMAGE_NOT_IN_RENDER_QUEUE
+        );
 
         require!(
-            uri.is_empty() == false,
-            "There is no URI associated to the attributes {} for {}.",
-            attributes,
-            name
+            &opt_queued_attributes.unwrap() == attributes,
+            ERR_ATTRIBUTES_MISMATCH
         );
 
-        return uri.get();
+        self.uris_of_attributes(equippable_token_id, attributes, name)
+            .set(uri);
+        self.attributes_to_render_by_name(equippable_token_id)
+            .remove(name);
+        self.enqueue_next_waiting_render(equippable_token_id, name);
     }
 }
@@ -0,0 +1,349 @@
+use crate::{
+    constants::*,
+    structs::{
+        equippable_attributes::EquippableAttributes, pending_customization::PendingCustomization,
+        token::Token,
+    },
+};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Instead of failing, a customization into attributes not rendered yet can be held in escrow.
+/// It is finalized when the renderer sets the URI of the attributes, or cancelled by its owner.
+#[elrond_wasm::module]
+pub trait EscrowModule:
+    super::storage::StorageModule
+    + super::uris::UrisModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+    + super::customization::CustomizationModule
+{
+    #[storage_mapper("escrow_enabled")]
+    fn escrow_enabled(&self, equippable_token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("pending_customization")]
+    fn pending_customization(
+        &self,
+        equippable: &Token<Self::Api>,
+    ) -> SingleValueMapper<PendingCustomization<Self::Api>>;
+
+    /// The equippables waiting for the image of the attributes.
+    #[storage_mapper("pending_customizations_of")]
+    fn pending_customizations_of(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> UnorderedSetMapper<Token<Self::Api>>;
+
+    /// The nonces of the equippables held in escrow for the collection.
+    #[storage_mapper("pending_customizations_by_collection")]
+    fn pending_customizations_by_collection(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("pending_customizations_by_owner")]
+    fn pending_customizations_by_owner(
+        &self,
+        owner: &ManagedAddress,
+    ) -> UnorderedSetMapper<Token<Self::Api>>;
+
+    /// When enabled, `customize` holds the tokens sent until the image of the new attributes is rendered.
+    #[endpoint(setEscrowEnabled)]
+    #[only_owner]
+    fn set_escrow_enabled(&self, equippable_token_id: TokenIdentifier, enabled: bool) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        self.escrow_enabled(&equippable_token_id).set(enabled);
+    }
+
+    #[view(isEscrowEnabled)]
+    fn is_escrow_enabled(&self, equippable_token_id: TokenIdentifier) -> bool {
+        return self.escrow_enabled(&equippable_token_id).get();
+    }
+
+    /// Send back the equippable and the items held in escrow, and refund the fee credit.
+    #[endpoint(cancelCustomization)]
+    fn cancel_customization(&self, equippable_token_id: TokenIdentifier, equippable_nonce: u64) {
+        let equippable = Token::new(equippable_token_id, equippable_nonce);
+
+        require!(
+            self.pending_customization(&equippable).is_empty() == false,
+            ERR_PENDING_CUSTOMIZATION_NOT_FOUND
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.pending_customization(&equippable).get().owner == caller,
+            ERR_NOT_PENDING_CUSTOMIZATION_OWNER
+        );
+
+        let pending = self.take_pending_customization(&equippable);
+
+        // the render is not needed anymore, unless other customizations wait for it
+        if self
+            .attributes_to_render_by_name(&equippable.token)
+            .get(&pending.name)
+            == Option::Some(pending.attributes.clone())
+            && self
+                .pending_customizations_of(&equippable.token, &pending.attributes, &pending.name)
+                .is_empty()
+        {
+            self.attributes_to_render_by_name(&equippable.token)
+                .remove(&pending.name);
+            self.enqueue_next_waiting_render(&equippable.token, &pending.name);
+        }
+
+        self.refund_pending_customization(&equippable, &pending);
+    }
+
+    /// Returns the equippables held in escrow for the owner, with the attributes they wait the image of.
+    #[view(getPendingCustomizations)]
+    fn get_pending_customizations(
+        &self,
+        owner: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, EquippableAttributes<Self::Api>>> {
+        let mut output = MultiValueEncoded::new();
+
+        for equippable in self.pending_customizations_by_owner(&owner).iter() {
+            let pending = self.pending_customization(&equippable).get();
+
+            output.push(MultiValue3::from((
+                equippable.token,
+                equippable.nonce,
+                pending.attributes,
+            )));
+        }
+
+        return output;
+    }
+
+    /// If escrow is enabled and the image of the new attributes is not rendered, hold the tokens and enqueue the render.
+    /// The customization is checked as if it was done now, and the fee is taken from the credit of the caller.
+    /// Returns false if the equippable can be customized right away.
+    fn hold_if_not_rendered(
+        &self,
+        equippable_payment: &EsdtTokenPayment<Self::Api>,
+        items_payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+        to_unequip_slots: &ManagedVec<ManagedBuffer<Self::Api>>,
+    ) -> bool {
+        let equippable_token_id = &equippable_payment.token_identifier;
+        let equippable_nonce = equippable_payment.token_nonce;
+
+        if self.escrow_enabled(equippable_token_id).get() == false {
+            return false;
+        }
+
+        require!(
+            equippable_payment.amount == BigUint::from(1u64),
+            ERR_MORE_THAN_ONE_EQUIPPABLE_RECEIVED
+        );
+
+        let mut tokens = ManagedVec::new();
+        for payment in items_payments.iter() {
+            require!(
+                payment.amount == BigUint::from(1u64),
+                ERR_MORE_THAN_ONE_ITEM_RECEIVED
+            );
+
+            tokens.push(Token::new(payment.token_identifier, payment.token_nonce));
+        }
+
+        let caller = self.blockchain().get_caller();
+        let mut attributes = EquippableAttributes::empty();
+        let mut sent_back = ManagedVec::new();
+
+        let name = match self.preview_customization(
+            &caller,
+            &self.blockchain().get_sc_address(),
+            equippable_token_id,
+            equippable_nonce,
+            to_unequip_slots,
+            &tokens,
+            &mut attributes,
+            &mut sent_back,
+        ) {
+            Result::Ok(name) => name,
+            Result::Err(error) => sc_panic!(error),
+        };
+
+        let uri_attributes = attributes.without_locks();
+        if self
+            .find_uri(equippable_token_id, &uri_attributes, &name)
+            .is_some()
+        {
+            return false;
+        }
+
+        let previous_attributes =
+            self.get_received_attributes(equippable_token_id, equippable_nonce);
+        let (fee_token, fee) = match self.get_customization_fee(
+            &caller,
+            equippable_token_id,
+            &previous_attributes,
+            &attributes,
+            items_payments.len(),
+        ) {
+            Result::Ok(Option::Some(fee)) => fee,
+            Result::Ok(Option::None) => (EgldOrEsdtTokenIdentifier::egld(), BigUint::zero()),
+            Result::Err(error) => sc_panic!(error),
+        };
+
+        if let Some(error) =
+            self.get_render_queue_conflict_error(equippable_token_id, &uri_attributes, &name)
+        {
+            sc_panic!(error);
+        }
+
+        self.attributes_to_render_by_name(equippable_token_id)
+            .insert(name.clone(), uri_attributes.clone());
+
+        if fee > 0 {
+            self.take_fee_credit(&caller, &fee_token, &fee);
+        }
+
+        self.record_customization(
+            equippable_token_id,
+            equippable_nonce,
+            equippable_nonce,
+            &caller,
+        );
+
+        // the unequipped items are taken out of the stock now, so that they are still there once finalized
+        let (_, new_attributes, moves) =
+            self.move_items(equippable_payment, items_payments, to_unequip_slots);
+
+        let equippable = Token::new(equippable_token_id.clone(), equippable_nonce);
+
+        self.pending_customizations_of(equippable_token_id, &uri_attributes, &name)
+            .insert(equippable.clone());
+        self.pending_customizations_by_collection(equippable_token_id)
+            .insert(equippable_nonce);
+        self.pending_customizations_by_owner(&caller)
+            .insert(equippable.clone());
+        self.pending_customization(&equippable)
+            .set(PendingCustomization {
+                owner: caller,
+                items: items_payments.clone(),
+                moves,
+                new_attributes,
+                attributes: uri_attributes,
+                name,
+                fee_token,
+                fee,
+            });
+
+        return true;
+    }
+
+    /// Returns an error if other attributes are already waiting to be rendered for the name.
+    fn get_render_queue_conflict_error(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> Option<&'static str> {
+        match self
+            .attributes_to_render_by_name(equippable_token_id)
+            .get(name)
+        {
+            Option::Some(queued_attributes) if &queued_attributes != attributes => {
+                return Option::Some(ERR_RENDER_ALREADY_IN_QUEUE);
+            }
+            _ => return Option::None,
+        }
+    }
+
+    /// Customize the equippables waiting for the attributes, and send them to their owner.
+    /// The cooldowns have been checked and started when the customization was held.
+    /// A customization that can't be completed anymore, e.g. because a role has been removed, is refunded instead of failing the render.
+    fn finalize_pending_customizations(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) {
+        let mut equippables = ManagedVec::<Self::Api, Token<Self::Api>>::new();
+        for equippable in self
+            .pending_customizations_of(equippable_token_id, attributes, name)
+            .iter()
+        {
+            equippables.push(equippable);
+        }
+
+        for equippable in equippables.iter() {
+            let pending = self.take_pending_customization(&equippable);
+
+            let error = self
+                .get_equippable_collection_roles_error(&equippable.token)
+                .or_else(|| self.get_item_moves_error(&pending.moves));
+
+            if error.is_some() {
+                self.refund_pending_customization(&equippable, &pending);
+                continue;
+            }
+
+            self.complete_item_moves(&pending.moves, &pending.owner);
+
+            if pending.fee > 0 {
+                self.split_fee_revenue(&pending.fee_token, &pending.fee);
+            }
+
+            let new_nonce = self.write_equippable(
+                &equippable.token,
+                equippable.nonce,
+                &pending.new_attributes,
+                &pending.owner,
+            );
+
+            self.move_lineage(&equippable.token, equippable.nonce, new_nonce);
+        }
+    }
+
+    /// Put the unequipped items back in the stock, and send back the equippable and the items received.
+    /// The fee is credited back to the owner.
+    fn refund_pending_customization(
+        &self,
+        equippable: &Token<Self::Api>,
+        pending: &PendingCustomization<Self::Api>,
+    ) {
+        self.revert_item_moves(&pending.moves);
+
+        if pending.fee > 0 {
+            self.add_fee_credit(&pending.owner, &pending.fee_token, &pending.fee);
+        }
+
+        let mut payments = ManagedVec::new();
+        payments.push(EsdtTokenPayment::new(
+            equippable.token.clone(),
+            equippable.nonce,
+            BigUint::from(1u32),
+        ));
+        payments.append_vec(pending.items.clone());
+
+        self.send().direct_multi(&pending.owner, &payments, &[]);
+    }
+
+    fn take_pending_customization(
+        &self,
+        equippable: &Token<Self::Api>,
+    ) -> PendingCustomization<Self::Api> {
+        let pending = self.pending_customization(equippable).get();
+
+        self.pending_customization(equippable).clear();
+        self.pending_customizations_of(&equippable.token, &pending.attributes, &pending.name)
+            .swap_remove(equippable);
+        self.pending_customizations_by_collection(&equippable.token)
+            .swap_remove(&equippable.nonce);
+        self.pending_customizations_by_owner(&pending.owner)
+            .swap_remove(equippable);
+
+        return pending;
+    }
+}
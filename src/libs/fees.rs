@@ -0,0 +1,381 @@
+use crate::{
+    constants::*,
+    structs::{equippable_attributes::EquippableAttributes, fee_schedule::FeeSchedule},
+};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Fees charged on customization, paid from a credit deposited beforehand by the users.
+/// The revenues are split between the beneficiaries, who claim them.
+#[elrond_wasm::module]
+pub trait FeesModule: super::storage::StorageModule {
+    /// The fee can be paid in any token of the schedules. Customization is free if empty.
+    #[storage_mapper("fee_schedules")]
+    fn fee_schedules(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, FeeSchedule<Self::Api>>;
+
+    /// Share of the revenues of each beneficiary, in basis points.
+    /// If empty, the owner receives all the revenues.
+    #[storage_mapper("fee_beneficiaries")]
+    fn fee_beneficiaries(&self) -> MapMapper<ManagedAddress, u32>;
+
+    #[storage_mapper("fee_credit_tokens")]
+    fn fee_credit_tokens(
+        &self,
+        owner: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("fee_credit")]
+    fn fee_credit(
+        &self,
+        owner: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Credits and revenues not withdrawn yet. They are kept out of the claim of the owner.
+    #[storage_mapper("fee_reserve")]
+    fn fee_reserve(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("fee_revenue_tokens")]
+    fn fee_revenue_tokens(
+        &self,
+        beneficiary: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("fee_revenue")]
+    fn fee_revenue(
+        &self,
+        beneficiary: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Accept a token to pay the customization fee of the collection, or update its schedule.
+    #[endpoint(setFeeSchedule)]
+    #[only_owner]
+    fn set_fee_schedule(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        token: EgldOrEsdtTokenIdentifier,
+        flat: BigUint,
+        per_slot: BigUint,
+        per_item: BigUint,
+    ) {
+        self.require_equippable_collection(&equippable_token_id);
+        require!(
+            token.is_egld() || token.is_valid_esdt_identifier(),
+            ERR_INVALID_PAYMENT_TOKEN
+        );
+
+        self.fee_schedules(&equippable_token_id).insert(
+            token,
+            FeeSchedule {
+                flat,
+                per_slot,
+                per_item,
+            },
+        );
+    }
+
+    #[endpoint(removeFeeSchedule)]
+    #[only_owner]
+    fn remove_fee_schedule(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        token: EgldOrEsdtTokenIdentifier,
+    ) {
+        require!(
+            self.fee_schedules(&equippable_token_id)
+                .remove(&token)
+                .is_some(),
+            ERR_FEE_SCHEDULE_NOT_FOUND
+        );
+    }
+
+    /// Replace the beneficiaries. Their shares, in basis points, must add up to 10 000.
+    #[endpoint(setFeeBeneficiaries)]
+    #[only_owner]
+    fn set_fee_beneficiaries(
+        &self,
+        beneficiaries: MultiValueEncoded<MultiValue2<ManagedAddress, u32>>,
+    ) {
+        self.fee_beneficiaries().clear();
+
+        let mut total_shares = 0u64;
+        for beneficiary in beneficiaries {
+            let (address, share) = beneficiary.into_tuple();
+
+            require!(share > 0, ERR_FEE_SHARE_IS_ZERO);
+
+            total_shares += share as u64;
+            require!(
+                self.fee_beneficiaries().insert(address, share).is_none(),
+                ERR_FEE_BENEFICIARY_DUPLICATED
+            );
+        }
+
+        require!(
+            self.fee_beneficiaries().is_empty() || total_shares == MAX_FEE_SHARES,
+            ERR_FEE_SHARES_MUST_ADD_UP_TO_TOTAL
+        );
+    }
+
+    #[view(getFeeSchedules)]
+    fn get_fee_schedules(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, FeeSchedule<Self::Api>>> {
+        let mut output = MultiValueEncoded::new();
+
+        for (token, fee_schedule) in self.fee_schedules(&equippable_token_id).iter() {
+            output.push(MultiValue2::from((token, fee_schedule)));
+        }
+
+        return output;
+    }
+
+    /// Returns each beneficiary with its share in basis points.
+    #[view(getFeeSplit)]
+    fn get_fee_split(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u32>> {
+        let mut output = MultiValueEncoded::new();
+
+        if self.fee_beneficiaries().is_empty() {
+            output.push(MultiValue2::from((
+                self.blockchain().get_owner_address(),
+                MAX_FEE_SHARES as u32,
+            )));
+        }
+
+        for (address, share) in self.fee_beneficiaries().iter() {
+            output.push(MultiValue2::from((address, share)));
+        }
+
+        return output;
+    }
+
+    #[payable("*")]
+    #[endpoint(depositFeeCredit)]
+    fn deposit_fee_credit(&self) {
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        let caller = self.blockchain().get_caller();
+
+        require!(amount > 0, ERR_NEED_FEE_CREDIT_PAYMENT);
+
+        self.add_fee_credit(&caller, &token, &amount);
+        self.fee_reserve(&token)
+            .update(|reserve| *reserve += &amount);
+    }
+
+    #[endpoint(withdrawFeeCredit)]
+    fn withdraw_fee_credit(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+
+        require!(amount > 0, ERR_FEE_CREDIT_WITHDRAWAL_IS_ZERO);
+
+        self.take_fee_credit(&caller, &token, &amount);
+        self.fee_reserve(&token)
+            .update(|reserve| *reserve -= &amount);
+
+        self.send().direct(&caller, &token, 0, &amount, &[]);
+    }
+
+    #[view(getFeeCredit)]
+    fn get_fee_credit(
+        &self,
+        owner: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        for token in self.fee_credit_tokens(&owner).iter() {
+            let credit = self.fee_credit(&owner, &token).get();
+
+            output.push(MultiValue2::from((token, credit)));
+        }
+
+        return output;
+    }
+
+    /// Send to the caller its share of the fees collected.
+    #[endpoint(claimFeeRevenues)]
+    fn claim_fee_revenues(&self) {
+        let caller = self.blockchain().get_caller();
+
+        for token in self.fee_revenue_tokens(&caller).iter() {
+            let revenue = self.fee_revenue(&caller, &token).get();
+            self.fee_revenue(&caller, &token).clear();
+            self.fee_reserve(&token)
+                .update(|reserve| *reserve -= &revenue);
+
+            self.send().direct(&caller, &token, 0, &revenue, &[]);
+        }
+
+        self.fee_revenue_tokens(&caller).clear();
+    }
+
+    #[view(getFeeRevenues)]
+    fn get_fee_revenues(
+        &self,
+        beneficiary: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        for token in self.fee_revenue_tokens(&beneficiary).iter() {
+            let revenue = self.fee_revenue(&beneficiary, &token).get();
+
+            output.push(MultiValue2::from((token, revenue)));
+        }
+
+        return output;
+    }
+
+    /// Returns the fee of a customization from the previous attributes to the new ones, with the number of items equipped.
+    /// The fee is in the first token of the schedules the payer has enough credit of; it is `None` if the customization is free.
+    fn get_customization_fee(
+        &self,
+        payer: &ManagedAddress,
+        equippable_token_id: &TokenIdentifier,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        attributes: &EquippableAttributes<Self::Api>,
+        items_count: usize,
+    ) -> Result<Option<(EgldOrEsdtTokenIdentifier, BigUint)>, &'static str> {
+        if self.fee_schedules(equippable_token_id).is_empty() {
+            return Result::Ok(Option::None);
+        }
+
+        let changed_slots_count = count_changed_slots(previous_attributes, attributes);
+
+        for (token, fee_schedule) in self.fee_schedules(equippable_token_id).iter() {
+            let fee = fee_schedule.get_fee(changed_slots_count, items_count);
+
+            if fee == 0 {
+                return Result::Ok(Option::None);
+            }
+
+            if fee <= self.fee_credit(payer, &token).get() {
+                return Result::Ok(Option::Some((token, fee)));
+            }
+        }
+
+        return Result::Err(ERR_NOT_ENOUGH_FEE_CREDIT);
+    }
+
+    /// Take the customization fee from the credit of the payer, and split it between the beneficiaries.
+    fn charge_customization_fee(
+        &self,
+        payer: &ManagedAddress,
+        equippable_token_id: &TokenIdentifier,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        attributes: &EquippableAttributes<Self::Api>,
+        items_count: usize,
+    ) {
+        let (token, fee) = match self.get_customization_fee(
+            payer,
+            equippable_token_id,
+            previous_attributes,
+            attributes,
+            items_count,
+        ) {
+            Result::Ok(Option::Some(fee)) => fee,
+            Result::Ok(Option::None) => return,
+            Result::Err(error) => sc_panic!(error),
+        };
+
+        self.take_fee_credit(payer, &token, &fee);
+        self.split_fee_revenue(&token, &fee);
+    }
+
+    fn split_fee_revenue(&self, token: &EgldOrEsdtTokenIdentifier, fee: &BigUint) {
+        if self.fee_beneficiaries().is_empty() {
+            self.add_fee_revenue(&self.blockchain().get_owner_address(), token, fee);
+            return;
+        }
+
+        // the rounding remainder goes to the last beneficiary
+        let beneficiaries_count = self.fee_beneficiaries().len();
+        let mut distributed = BigUint::zero();
+
+        for (index, (address, share)) in self.fee_beneficiaries().iter().enumerate() {
+            let revenue = if index + 1 == beneficiaries_count {
+                fee - &distributed
+            } else {
+                fee * share / MAX_FEE_SHARES
+            };
+
+            distributed += &revenue;
+            self.add_fee_revenue(&address, token, &revenue);
+        }
+    }
+
+    fn add_fee_revenue(
+        &self,
+        beneficiary: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        if amount == &0 {
+            return;
+        }
+
+        self.fee_revenue(beneficiary, token)
+            .update(|revenue| *revenue += amount);
+        self.fee_revenue_tokens(beneficiary).insert(token.clone());
+    }
+
+    fn add_fee_credit(
+        &self,
+        owner: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.fee_credit(owner, token)
+            .update(|credit| *credit += amount);
+        self.fee_credit_tokens(owner).insert(token.clone());
+    }
+
+    fn take_fee_credit(
+        &self,
+        owner: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let credit = self.fee_credit(owner, token).get();
+
+        require!(amount <= &credit, ERR_NOT_ENOUGH_FEE_CREDIT);
+
+        if amount == &credit {
+            self.fee_credit(owner, token).clear();
+            self.fee_credit_tokens(owner).swap_remove(token);
+        } else {
+            self.fee_credit(owner, token).set(credit - amount);
+        }
+    }
+}
+
+/// Number of slots whose item differs between both attributes.
+pub fn count_changed_slots<M: ManagedTypeApi>(
+    previous_attributes: &EquippableAttributes<M>,
+    attributes: &EquippableAttributes<M>,
+) -> usize {
+    let mut count = 0;
+
+    for slot in attributes.get_slots().iter() {
+        if previous_attributes.get_name(&slot) != attributes.get_name(&slot) {
+            count += 1;
+        }
+    }
+
+    for slot in previous_attributes.get_slots().iter() {
+        if attributes.get_slots().contains(&slot) == false
+            && previous_attributes.get_name(&slot) != attributes.get_name(&slot)
+        {
+            count += 1;
+        }
+    }
+
+    return count;
+}
@@ -0,0 +1,272 @@
+use crate::{
+    constants::*,
+    structs::{item::Item, item_inventory::ItemInventory, token::Token},
+};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Keep track of the item tokens deposited, equipped and paid out, instead of relying on the contract balance.
+#[elrond_wasm::module]
+pub trait InventoryModule: super::storage::StorageModule {
+    #[storage_mapper("inventory")]
+    fn inventory(&self, token: &Token<Self::Api>) -> SingleValueMapper<ItemInventory<Self::Api>>;
+
+    /// Stock deposited by suppliers, that the owner cannot withdraw.
+    #[storage_mapper("reserved_stock")]
+    fn reserved_stock(&self, token: &Token<Self::Api>) -> SingleValueMapper<BigUint>;
+
+    /// Quantity held in the vaults of the users, which is not part of the stock.
+    #[storage_mapper("vault_stock")]
+    fn vault_stock(&self, token: &Token<Self::Api>) -> SingleValueMapper<BigUint>;
+
+    /// Set by the first deposit or customization. The legacy inventory cannot be migrated anymore.
+    #[storage_mapper("inventory_ledger_started")]
+    fn inventory_ledger_started(&self) -> SingleValueMapper<bool>;
+
+    /// Items whose available stock is lower or equal are reported by `getLowStockItems`.
+    #[storage_mapper("low_stock_threshold")]
+    fn low_stock_threshold(&self) -> SingleValueMapper<BigUint>;
+
+    #[endpoint(setLowStockThreshold)]
+    #[only_owner]
+    fn set_low_stock_threshold(&self, threshold: BigUint) {
+        self.low_stock_threshold().set(threshold);
+    }
+
+    /// Send back item tokens to the owner. Only the tokens that are not equipped, nor deposited by a supplier, can be withdrawn.
+    #[endpoint(withdrawItems)]
+    #[only_owner]
+    fn withdraw_items(&self, items: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>) {
+        let caller = self.blockchain().get_caller();
+
+        for item in items {
+            let (token_id, token_nonce, amount) = item.into_tuple();
+            let token = Token::new(token_id, token_nonce);
+
+            let available = self.get_inventory_of(&token).get_available();
+            let reserved = self.reserved_stock(&token).get();
+            require!(
+                available >= reserved && amount <= available - reserved,
+                ERR_NOT_ENOUGH_AVAILABLE_STOCK
+            );
+
+            self.record_withdrawal(&token, &amount);
+
+            self.send()
+                .direct_esdt(&caller, &token.token, token.nonce, &amount, &[]);
+        }
+    }
+
+    /// Record the item tokens held before the ledger was kept, from the balance of the contract, with the quantity carried by equippables.
+    /// The tokens held beyond the recorded stock and the vaults, and not equipped, are counted as deposited.
+    /// Every item token must be listed in a single call, made before any deposit or customization.
+    #[endpoint(migrateLegacyInventory)]
+    #[only_owner]
+    fn migrate_legacy_inventory(
+        &self,
+        tokens: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>,
+    ) {
+        require!(
+            self.inventory_ledger_started().get() == false,
+            ERR_LEGACY_INVENTORY_MIGRATION_CLOSED
+        );
+
+        for token in tokens {
+            let (token_id, token_nonce, equipped) = token.into_tuple();
+            let token = Token::new(token_id, token_nonce);
+
+            let balance = self.blockchain().get_sc_balance(
+                &EgldOrEsdtTokenIdentifier::esdt(token.token.clone()),
+                token.nonce,
+            );
+            let mut inventory = self.get_inventory_of(&token);
+            let recorded = &inventory.stock + &self.vault_stock(&token).get();
+
+            require!(
+                balance >= &recorded + &equipped,
+                ERR_LEGACY_EQUIPPED_ABOVE_BALANCE
+            );
+
+            let legacy = balance - recorded;
+            inventory.deposited += &legacy - &equipped;
+            inventory.equipped += &equipped;
+            inventory.stock += legacy;
+
+            self.inventory(&token).set(&inventory);
+        }
+
+        self.start_inventory_ledger();
+    }
+
+    #[view(getInventory)]
+    fn get_inventory(
+        &self,
+        token_id: TokenIdentifier,
+        token_nonce: u64,
+    ) -> ItemInventory<Self::Api> {
+        return self.get_inventory_of(&Token::new(token_id, token_nonce));
+    }
+
+    /// Returns the available stock of every token registered for the collection.
+    #[view(getStock)]
+    fn get_stock(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>> {
+        return self.get_stock_below(&equippable_token_id, Option::None);
+    }
+
+    #[view(getLowStockItems)]
+    fn get_low_stock_items(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>> {
+        return self.get_stock_below(
+            &equippable_token_id,
+            Option::Some(self.low_stock_threshold().get()),
+        );
+    }
+
+    fn get_stock_below(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        opt_threshold: Option<BigUint>,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        for item in self.registered_items(equippable_token_id).iter() {
+            for token in self.tokens_of_item(equippable_token_id, &item).iter() {
+                let available = self.get_inventory_of(&token).get_available();
+
+                let is_below = match &opt_threshold {
+                    Some(threshold) => &available <= threshold,
+                    None => true,
+                };
+
+                if is_below {
+                    output.push(MultiValue3::from((token.token, token.nonce, available)));
+                }
+            }
+        }
+
+        return output;
+    }
+
+    fn get_inventory_of(&self, token: &Token<Self::Api>) -> ItemInventory<Self::Api> {
+        if self.inventory(token).is_empty() {
+            return ItemInventory::default();
+        } else {
+            return self.inventory(token).get();
+        }
+    }
+
+    fn start_inventory_ledger(&self) {
+        self.inventory_ledger_started().set(true);
+    }
+
+    fn record_deposit(&self, token: &Token<Self::Api>, amount: &BigUint) {
+        self.start_inventory_ledger();
+
+        let mut inventory = self.get_inventory_of(token);
+
+        inventory.deposited += amount;
+        inventory.stock += amount;
+
+        self.inventory(token).set(&inventory);
+    }
+
+    fn record_withdrawal(&self, token: &Token<Self::Api>, amount: &BigUint) {
+        let mut inventory = self.get_inventory_of(token);
+
+        require!(
+            amount <= &inventory.get_available(),
+            ERR_NOT_ENOUGH_AVAILABLE_STOCK
+        );
+
+        inventory.withdrawn += amount;
+        inventory.stock -= amount;
+
+        self.inventory(token).set(&inventory);
+    }
+
+    /// The token has been sent by a user to be equipped.
+    fn record_equip(&self, token: &Token<Self::Api>) {
+        let mut inventory = self.get_inventory_of(token);
+
+        inventory.equipped += 1u32;
+        inventory.stock += 1u32;
+
+        self.inventory(token).set(&inventory);
+    }
+
+    /// The token is sent to a user, because an item has been unequipped.
+    /// Returns the token whose equipped count has been released, see `release_equipped`.
+    fn record_payout(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        token: &Token<Self::Api>,
+    ) -> Option<Token<Self::Api>> {
+        let mut inventory = self.get_inventory_of(token);
+
+        let item_id = &token.token;
+        let item_nonce = token.nonce;
+        require!(
+            inventory.stock > 0,
+            "Can't send {}-{:x} items to the user. There is no SFT remaining.",
+            item_id,
+            item_nonce
+        );
+
+        inventory.paid_out += 1u32;
+        inventory.stock -= 1u32;
+
+        self.inventory(token).set(&inventory);
+
+        return self.release_equipped(equippable_token_id, item, token);
+    }
+
+    /// An item equipped with one of its tokens can be unequipped with another one.
+    /// The equipped count is released from the token paid out if it has one, otherwise from another token of the item.
+    fn release_equipped(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        token: &Token<Self::Api>,
+    ) -> Option<Token<Self::Api>> {
+        for candidate in core::iter::once(token.clone())
+            .chain(self.tokens_of_item(equippable_token_id, item).iter())
+        {
+            let mut inventory = self.get_inventory_of(&candidate);
+
+            if inventory.equipped > 0 {
+                inventory.equipped -= 1u32;
+                self.inventory(&candidate).set(&inventory);
+
+                return Option::Some(candidate);
+            }
+        }
+
+        return Option::None;
+    }
+
+    /// The token paid out for a customization held in escrow is back in the stock, because the customization has been refunded.
+    fn cancel_payout(&self, token: &Token<Self::Api>) {
+        let mut inventory = self.get_inventory_of(token);
+
+        inventory.paid_out -= 1u32;
+        inventory.stock += 1u32;
+
+        self.inventory(token).set(&inventory);
+    }
+
+    /// The equipped count released by a refunded payout is restored.
+    fn cancel_release(&self, token: &Token<Self::Api>) {
+        let mut inventory = self.get_inventory_of(token);
+
+        inventory.equipped += 1u32;
+
+        self.inventory(token).set(&inventory);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    constants::*,
+    structs::{
+        equippable_attributes::EquippableAttributes, item::Item, item_moves::ItemMoves,
+        token::Token,
+    },
+};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Named outfits saved by the users, that can be applied to any of their equippables.
+#[elrond_wasm::module]
+pub trait LoadoutsModule:
+    super::customize::CustomizeModule
+    + super::storage::StorageModule
+    + super::equippable_uris::EquippableUrisModule
+    + super::uris::UrisModule
+    + super::treasury::TreasuryModule
+    + super::pause::PauseModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+    + super::customization::CustomizationModule
+    + super::escrow::EscrowModule
+    + super::vault::VaultModule
+{
+    #[storage_mapper("loadouts")]
+    fn loadouts(
+        &self,
+        owner: &ManagedAddress,
+        equippable_token_id: &TokenIdentifier,
+    ) -> MapMapper<ManagedBuffer<Self::Api>, EquippableAttributes<Self::Api>>;
+
+    /// Save the attributes under a name. Saving again with the same name overrides the loadout.
+    #[endpoint(saveLoadout)]
+    fn save_loadout(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        name: ManagedBuffer<Self::Api>,
+        attributes: EquippableAttributes<Self::Api>,
+    ) {
+        self.require_equippable_collection(&equippable_token_id);
+        self.require_attributes_match_slots(&equippable_token_id, &attributes);
+        self.require_no_conflicting_slots_equipped(&equippable_token_id, &attributes);
+        self.require_items_requirements_met(&equippable_token_id, &attributes, &attributes);
+
+        for item in attributes.get_equipped_items().iter() {
+            require!(
+                self.has_item(&equippable_token_id, &item)
+                    || self.is_default_item_equipped(&equippable_token_id, &attributes, &item.slot),
+                ERR_ITEM_NOT_REGISTERED
+            );
+        }
+
+        self.loadouts(&self.blockchain().get_caller(), &equippable_token_id)
+            .insert(name, attributes.without_locks());
+    }
+
+    #[endpoint(removeLoadout)]
+    fn remove_loadout(&self, equippable_token_id: TokenIdentifier, name: ManagedBuffer<Self::Api>) {
+        require!(
+            self.loadouts(&self.blockchain().get_caller(), &equippable_token_id)
+                .remove(&name)
+                .is_some(),
+            ERR_LOADOUT_NOT_FOUND
+        );
+    }
+
+    /// Equip the loadout on the equippable sent.
+    /// The missing items are taken from the tokens sent after the equippable, then from the vault of the caller.
+    /// The items that are not part of the loadout are sent back to the caller.
+    #[payable("*")]
+    #[endpoint(applyLoadout)]
+    fn apply_loadout(&self, name: ManagedBuffer<Self::Api>) -> u64 {
+        self.require_not_paused(b"applyLoadout");
+
+        let payments = self.call_value().all_esdt_transfers();
+
+        require!(payments.len() >= 1, ERR_NEED_EQUIPPABLE);
+
+        let equippable_payment = payments.get(0);
+        let equippable_token_id = equippable_payment.token_identifier;
+        let equippable_nonce = equippable_payment.token_nonce;
+
+        require!(
+            self.equippable_token_ids().contains(&equippable_token_id),
+            ERR_FIRST_PAYMENT_IS_EQUIPPABLE
+        );
+        require!(
+            equippable_payment.amount == BigUint::from(1u64),
+            ERR_MORE_THAN_ONE_EQUIPPABLE_RECEIVED
+        );
+
+        self.require_equippable_collection_roles_set(&equippable_token_id);
+
+        let caller = self.blockchain().get_caller();
+        let loadout = match self.loadouts(&caller, &equippable_token_id).get(&name) {
+            Some(loadout) => loadout,
+            None => sc_panic!(ERR_LOADOUT_NOT_FOUND),
+        };
+
+        let mut attributes = self.get_received_attributes(&equippable_token_id, equippable_nonce);
+        let previous_attributes = attributes.clone();
+        let mut moves = ItemMoves::default();
+
+        let mut unused_payments = payments.slice(1, payments.len()).unwrap_or_default();
+
+        // first, unequip the items that are not part of the loadout
+        for item in attributes.get_equipped_items().iter() {
+            if loadout.get_name(&item.slot) == Option::Some(item.name.clone())
+                || self.is_default_item_equipped(&equippable_token_id, &attributes, &item.slot)
+            {
+                continue;
+            }
+
+            self.unequip_slot(
+                &equippable_token_id,
+                &mut attributes,
+                &item.slot,
+                &mut moves,
+            );
+        }
+
+        // then, equip the missing ones
+        let mut items_count = 0;
+        for item in loadout.get_equipped_items().iter() {
+            if attributes.get_name(&item.slot) == Option::Some(item.name.clone()) {
+                continue;
+            }
+
+            let token =
+                self.take_loadout_item(&equippable_token_id, &item, &caller, &mut unused_payments);
+
+            self.equip_slot(&equippable_token_id, &mut attributes, &item, &mut moves);
+            self.receive_item(&equippable_token_id, &item, &token, &mut moves);
+            items_count += 1;
+        }
+
+        require!(unused_payments.len() == 0, ERR_LOADOUT_PAYMENT_NOT_USED);
+
+        self.require_customized_attributes_match_slots(
+            &equippable_token_id,
+            &previous_attributes,
+            &attributes,
+        );
+        self.require_items_requirements_met(
+            &equippable_token_id,
+            &previous_attributes,
+            &attributes,
+        );
+        self.charge_customization_fee(
+            &caller,
+            &equippable_token_id,
+            &previous_attributes,
+            &attributes,
+            items_count,
+        );
+        self.complete_item_moves(&moves, &caller);
+
+        return self.update_equippable(
+            &equippable_token_id,
+            equippable_nonce,
+            &attributes,
+            &caller,
+            false,
+        );
+    }
+
+    #[view(getLoadouts)]
+    fn get_loadouts(
+        &self,
+        owner: ManagedAddress,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer<Self::Api>, EquippableAttributes<Self::Api>>>
+    {
+        let mut output = MultiValueEncoded::new();
+
+        for (name, attributes) in self.loadouts(&owner, &equippable_token_id).iter() {
+            output.push(MultiValue2::from((name, attributes)));
+        }
+
+        return output;
+    }
+
+    /// Returns a token of the item, taken from the payments, or from the vault of the owner.
+    fn take_loadout_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        owner: &ManagedAddress,
+        payments: &mut ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) -> Token<Self::Api> {
+        let opt_index = payments.iter().position(|payment| {
+            let token = Token::new(payment.token_identifier, payment.token_nonce);
+
+            return self.get_item(equippable_token_id, &token) == Option::Some(item.clone());
+        });
+
+        if let Some(index) = opt_index {
+            let payment = payments.get(index);
+            payments.remove(index);
+
+            require!(
+                payment.amount == BigUint::from(1u64),
+                ERR_MORE_THAN_ONE_ITEM_RECEIVED
+            );
+
+            return Token::new(payment.token_identifier, payment.token_nonce);
+        }
+
+        for token in self.tokens_of_item(equippable_token_id, item).iter() {
+            if self.vault_balance(owner, &token).get() > 0 {
+                self.take_from_vault(owner, &token, &BigUint::from(1u32));
+
+                return token;
+            }
+        }
+
+        sc_panic!(
+            "The item {} of the slot {} is missing. Send it, or deposit it in your vault.",
+            item.name,
+            item.slot
+        );
+    }
+}
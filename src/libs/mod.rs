@@ -1,3 +1,19 @@
+pub mod cooldowns;
+pub mod customization;
 pub mod customize;
 pub mod equippable_uris;
+pub mod escrow;
+pub mod fees;
+pub mod inventory;
+pub mod loadouts;
+pub mod pause;
+pub mod preview;
+pub mod proxies;
+pub mod requirements;
+pub mod simulation;
+pub mod slots;
 pub mod storage;
+pub mod suppliers;
+pub mod treasury;
+pub mod uris;
+pub mod vault;
@@ -0,0 +1,110 @@
+use crate::constants::*;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Emergency stop of the whole contract, or of some endpoints.
+/// Withdrawals stay available while paused, so the users can always get their tokens back.
+#[elrond_wasm::module]
+pub trait PauseModule {
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("paused_endpoints")]
+    fn paused_endpoints(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    /// Addresses allowed to pause, in addition to the owner. Only the owner can unpause.
+    #[storage_mapper("pausers")]
+    fn pausers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[endpoint(addPauser)]
+    #[only_owner]
+    fn add_pauser(&self, pauser: ManagedAddress) {
+        self.pausers().insert(pauser);
+    }
+
+    #[endpoint(removePauser)]
+    #[only_owner]
+    fn remove_pauser(&self, pauser: ManagedAddress) {
+        require!(self.pausers().swap_remove(&pauser), ERR_PAUSER_NOT_FOUND);
+    }
+
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.require_owner_or_pauser();
+
+        self.paused().set(true);
+    }
+
+    #[endpoint(unpause)]
+    #[only_owner]
+    fn unpause(&self) {
+        self.paused().clear();
+    }
+
+    #[endpoint(pauseEndpoints)]
+    fn pause_endpoints(&self, endpoints: MultiValueEncoded<ManagedBuffer>) {
+        self.require_owner_or_pauser();
+
+        for endpoint in endpoints {
+            require!(
+                PAUSABLE_ENDPOINTS
+                    .iter()
+                    .any(|pausable_endpoint| &endpoint == *pausable_endpoint),
+                ERR_ENDPOINT_NOT_PAUSABLE
+            );
+
+            self.paused_endpoints().insert(endpoint);
+        }
+    }
+
+    #[endpoint(unpauseEndpoints)]
+    #[only_owner]
+    fn unpause_endpoints(&self, endpoints: MultiValueEncoded<ManagedBuffer>) {
+        for endpoint in endpoints {
+            self.paused_endpoints().swap_remove(&endpoint);
+        }
+    }
+
+    /// Returns whether the whole contract is paused, then the endpoints paused.
+    #[view(getPauseState)]
+    fn get_pause_state(&self) -> MultiValue2<bool, ManagedVec<ManagedBuffer>> {
+        let mut paused_endpoints = ManagedVec::new();
+
+        for endpoint in self.paused_endpoints().iter() {
+            paused_endpoints.push(endpoint);
+        }
+
+        return MultiValue2::from((self.paused().get(), paused_endpoints));
+    }
+
+    fn require_not_paused(&self, endpoint: &[u8]) {
+        if let Some(error) = self.get_pause_error(endpoint) {
+            sc_panic!(error);
+        }
+    }
+
+    fn get_pause_error(&self, endpoint: &[u8]) -> Option<&'static str> {
+        if self.paused().get() {
+            return Option::Some(ERR_CONTRACT_PAUSED);
+        }
+
+        if self
+            .paused_endpoints()
+            .contains(&ManagedBuffer::new_from_bytes(endpoint))
+        {
+            return Option::Some(ERR_ENDPOINT_PAUSED);
+        }
+
+        return Option::None;
+    }
+
+    fn require_owner_or_pauser(&self) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            caller == self.blockchain().get_owner_address() || self.pausers().contains(&caller),
+            ERR_NOT_OWNER_OR_PAUSER
+        );
+    }
+}
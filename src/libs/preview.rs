@@ -0,0 +1,479 @@
+use crate::{
+    constants::*,
+    structs::{
+        equippable_attributes::EquippableAttributes, item::Item, supply_mode::SupplyMode,
+        token::Token, update_mode::UpdateMode,
+    },
+};
+use core::ops::Deref;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The checks of a customization, returning the error instead of panicking.
+/// CustomizeModule panics with these errors, and SimulationModule returns them, so both fail the same way.
+#[elrond_wasm::module]
+pub trait PreviewModule:
+    super::storage::StorageModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+{
+    /// Compute the attributes and the tokens sent back if the equippable was customized now.
+    /// The equippable is read from the holder: the owner, or the smart contract once it has received it.
+    /// Returns the name of the equippable.
+    fn preview_customization(
+        &self,
+        owner: &ManagedAddress,
+        holder: &ManagedAddress,
+        equippable_token_id: &TokenIdentifier,
+        equippable_nonce: u64,
+        to_unequip_slots: &ManagedVec<ManagedBuffer<Self::Api>>,
+        tokens: &ManagedVec<Token<Self::Api>>,
+        attributes: &mut EquippableAttributes<Self::Api>,
+        sent_back: &mut ManagedVec<Token<Self::Api>>,
+    ) -> Result<ManagedBuffer<Self::Api>, ManagedBuffer<Self::Api>> {
+        check(
+            tokens.len() >= 1 || to_unequip_slots.len() >= 1,
+            ERR_NEED_ONE_ITEM_OR_UNEQUIP_SLOT,
+        )?;
+        check(
+            self.equippable_token_ids().contains(equippable_token_id),
+            ERR_FIRST_PAYMENT_IS_EQUIPPABLE,
+        )?;
+        check_error(self.get_equippable_collection_roles_error(equippable_token_id))?;
+
+        let esdt_data =
+            self.blockchain()
+                .get_esdt_token_data(holder, equippable_token_id, equippable_nonce);
+        check(esdt_data.amount > 0, ERR_EQUIPPABLE_NOT_OWNED)?;
+
+        *attributes = esdt_data.decode_attributes::<EquippableAttributes<Self::Api>>();
+        self.migrate_remapped_items(equippable_token_id, attributes);
+
+        let previous_attributes = attributes.clone();
+        let mut received = ManagedVec::new();
+
+        // first unequip
+        for slot in to_unequip_slots.iter() {
+            self.preview_unequip_slot(
+                equippable_token_id,
+                attributes,
+                &slot,
+                sent_back,
+                &received,
+            )?;
+        }
+
+        // then, equip
+        for token in tokens.iter() {
+            let item = self.get_item_to_equip(equippable_token_id, &token)?;
+
+            for slot in self
+                .get_slots_to_clear(equippable_token_id, attributes, &item)
+                .iter()
+            {
+                self.preview_unequip_slot(
+                    equippable_token_id,
+                    attributes,
+                    &slot,
+                    sent_back,
+                    &received,
+                )?;
+            }
+
+            attributes.set_item(&item.slot, Option::Some(item.name.clone()));
+            self.lock_equipped_item(equippable_token_id, attributes, &item);
+
+            check_error(self.get_receive_item_error(equippable_token_id, &item, &token))?;
+
+            if self.is_item_kept_in_custody(equippable_token_id, &item) {
+                received.push(token);
+            }
+        }
+
+        self.check_customized_attributes_match_slots(
+            equippable_token_id,
+            &previous_attributes,
+            attributes,
+        )?;
+        check_error(self.get_items_requirements_error(
+            equippable_token_id,
+            &previous_attributes,
+            attributes,
+        ))?;
+
+        if let Result::Err(error) = self.get_customization_fee(
+            owner,
+            equippable_token_id,
+            &previous_attributes,
+            attributes,
+            tokens.len(),
+        ) {
+            return Result::Err(ManagedBuffer::new_from_bytes(error.as_bytes()));
+        }
+
+        check_error(self.get_cooldown_error(equippable_token_id, equippable_nonce, owner, false))?;
+
+        return Result::Ok(esdt_data.name);
+    }
+
+    /// Empty the slot provided, or equip its default item if the slot is mandatory.
+    /// `sent_back` and `received` are the tokens sent and received earlier in the same customization, not recorded in the stock yet.
+    /// Returns the item unequipped, and the token to send back unless the item is consumable.
+    fn preview_unequip_slot(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &mut EquippableAttributes<Self::Api>,
+        slot: &ManagedBuffer<Self::Api>,
+        sent_back: &mut ManagedVec<Token<Self::Api>>,
+        received: &ManagedVec<Token<Self::Api>>,
+    ) -> Result<(Item<Self::Api>, Option<Token<Self::Api>>), ManagedBuffer<Self::Api>> {
+        let name = match attributes.get_name(slot) {
+            Some(name) => name,
+            None => {
+                return Result::Err(ManagedBuffer::new_from_bytes(
+                    ERR_CANNOT_UNEQUIP_EMPTY_SLOT.as_bytes(),
+                ))
+            }
+        };
+
+        check(
+            self.is_default_item_equipped(equippable_token_id, attributes, slot) == false,
+            ERR_CANNOT_UNEQUIP_MANDATORY_SLOT,
+        )?;
+
+        if attributes.get_unlock_timestamp(slot) > self.blockchain().get_block_timestamp() {
+            return Result::Err(sc_format!("The slot {} is locked.", slot));
+        }
+
+        let item = Item {
+            slot: slot.clone(),
+            name,
+        };
+
+        let (token, is_registered) = if self.has_item(equippable_token_id, &item) {
+            let token = self.get_token_to_supply(equippable_token_id, &item, sent_back, received);
+
+            (token, true)
+        } else if self
+            .unregistered_items(equippable_token_id, &item)
+            .is_empty()
+            == false
+        {
+            (
+                self.unregistered_items(equippable_token_id, &item).get(),
+                false,
+            )
+        } else {
+            return Result::Err(sc_format!(
+                "The item you are unequipping at slot {} is not registered.",
+                slot
+            ));
+        };
+
+        // consumable items have been burned on equip
+        let opt_token = if self.consumable_items(equippable_token_id).contains(&item) {
+            Option::None
+        } else {
+            match self.check_item_supply(equippable_token_id, &item, &token, sent_back, received) {
+                Result::Ok(()) => {
+                    sent_back.push(token.clone());
+
+                    Option::Some(token)
+                }
+                // an unregistered item is unequipped even if its token cannot be sent back
+                Result::Err(_) if is_registered == false => Option::None,
+                Result::Err(error) => return Result::Err(error),
+            }
+        };
+
+        match self.default_items(equippable_token_id).get(slot) {
+            Some(default_name) => attributes.set_item(slot, Option::Some(default_name)),
+            None => attributes.empty_slot(slot),
+        }
+
+        return Result::Ok((item, opt_token));
+    }
+
+    /// Returns the preferred token of the item, or another of its tokens if the preferred one is out of stock.
+    fn get_token_to_supply(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        sent_back: &ManagedVec<Token<Self::Api>>,
+        received: &ManagedVec<Token<Self::Api>>,
+    ) -> Token<Self::Api> {
+        let preferred_token = self
+            .preferred_token_of_item(equippable_token_id, item)
+            .get();
+
+        if self.supply_mode(equippable_token_id, item).get() == SupplyMode::Mint
+            || self.has_stock_to_send(&preferred_token, sent_back, received)
+        {
+            return preferred_token;
+        }
+
+        for token in self.tokens_of_item(equippable_token_id, item).iter() {
+            if self.has_stock_to_send(&token, sent_back, received) {
+                return token;
+            }
+        }
+
+        return preferred_token;
+    }
+
+    fn has_stock_to_send(
+        &self,
+        token: &Token<Self::Api>,
+        sent_back: &ManagedVec<Token<Self::Api>>,
+        received: &ManagedVec<Token<Self::Api>>,
+    ) -> bool {
+        let stock = self.get_inventory_of(token).stock + count_of(received, token) as u64;
+
+        return stock > count_of(sent_back, token) as u64;
+    }
+
+    /// Make sure that a token of the item can be sent back, from the stock or by minting it.
+    fn check_item_supply(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        token: &Token<Self::Api>,
+        sent_back: &ManagedVec<Token<Self::Api>>,
+        received: &ManagedVec<Token<Self::Api>>,
+    ) -> Result<(), ManagedBuffer<Self::Api>> {
+        match self.supply_mode(equippable_token_id, item).get() {
+            SupplyMode::Custody => {
+                if self.has_stock_to_send(token, sent_back, received) == false {
+                    return Result::Err(sc_format!(
+                        "Can't send {}-{:x} items to the user. There is no SFT remaining.",
+                        token.token,
+                        token.nonce
+                    ));
+                }
+
+                return Result::Ok(());
+            }
+            SupplyMode::Mint => return check_error(self.get_item_roles_error(&token.token)),
+        }
+    }
+
+    fn get_item_to_equip(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        token: &Token<Self::Api>,
+    ) -> Result<Item<Self::Api>, ManagedBuffer<Self::Api>> {
+        if self.has_token(equippable_token_id, token) == false {
+            return Result::Err(sc_format!(
+                "The item you are equipping {} {} is not registered.",
+                token.token.as_managed_buffer(),
+                token.nonce
+            ));
+        }
+
+        return Result::Ok(self.item_of_token(equippable_token_id, token).get());
+    }
+
+    /// Returns the slots to unequip before equipping the item: its own slot, then the slots that cannot be worn with it.
+    /// The default item of a mandatory slot has no token, so it is simply replaced.
+    fn get_slots_to_clear(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        item: &Item<Self::Api>,
+    ) -> ManagedVec<ManagedBuffer<Self::Api>> {
+        let mut slots = ManagedVec::new();
+
+        if attributes.is_slot_empty(&item.slot) == false
+            && self.is_default_item_equipped(equippable_token_id, attributes, &item.slot) == false
+        {
+            slots.push(item.slot.clone());
+        }
+
+        for conflicting_slot in self
+            .get_conflicting_slots(equippable_token_id, &item.slot)
+            .iter()
+        {
+            if attributes.is_slot_empty(&conflicting_slot) == false {
+                slots.push(conflicting_slot.deref().clone());
+            }
+        }
+
+        return slots;
+    }
+
+    /// Returns the attributes of an equippable received by the smart contract, with the remapped items migrated.
+    fn get_received_attributes(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        equippable_nonce: u64,
+    ) -> EquippableAttributes<Self::Api> {
+        let mut attributes = self
+            .blockchain()
+            .get_esdt_token_data(
+                &self.blockchain().get_sc_address(),
+                equippable_token_id,
+                equippable_nonce,
+            )
+            .decode_attributes::<EquippableAttributes<Self::Api>>();
+
+        self.migrate_remapped_items(equippable_token_id, &mut attributes);
+
+        return attributes;
+    }
+
+    /// Lock the slot of the item just equipped, if the item has a lock duration.
+    fn lock_equipped_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &mut EquippableAttributes<Self::Api>,
+        item: &Item<Self::Api>,
+    ) {
+        let lock_duration = self.item_lock_duration(equippable_token_id, item).get();
+        if lock_duration == PERMANENT_LOCK {
+            attributes.lock_slot(&item.slot, PERMANENT_LOCK);
+        } else if lock_duration > 0 {
+            let unlock_timestamp = self.blockchain().get_block_timestamp() + lock_duration;
+            attributes.lock_slot(&item.slot, unlock_timestamp);
+        }
+    }
+
+    /// Replace the items that have been remapped by the owner with their new name.
+    fn migrate_remapped_items(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &mut EquippableAttributes<Self::Api>,
+    ) {
+        for item in attributes.get_equipped_items().iter() {
+            let resolved_item = self.resolve_remapped_item(equippable_token_id, &item);
+
+            if resolved_item != item {
+                let unlock_timestamp = attributes.get_unlock_timestamp(&item.slot);
+
+                attributes.set_item(&resolved_item.slot, Option::Some(resolved_item.name));
+
+                if unlock_timestamp > 0 {
+                    attributes.lock_slot(&resolved_item.slot, unlock_timestamp);
+                }
+            }
+        }
+    }
+
+    fn is_item_kept_in_custody(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> bool {
+        return self.consumable_items(equippable_token_id).contains(item) == false
+            && self.supply_mode(equippable_token_id, item).get() == SupplyMode::Custody;
+    }
+
+    /// Consumable items and items in mint mode are burned when equipped.
+    fn get_receive_item_error(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        token: &Token<Self::Api>,
+    ) -> Option<&'static str> {
+        if self.consumable_items(equippable_token_id).contains(item) {
+            return self.get_item_burn_role_error(&token.token);
+        }
+
+        match self.supply_mode(equippable_token_id, item).get() {
+            SupplyMode::Custody => return Option::None,
+            SupplyMode::Mint => return self.get_item_roles_error(&token.token),
+        }
+    }
+
+    /// Make sure that the smart contract can mint and burn the item.
+    fn require_item_roles_set(&self, token_id: &TokenIdentifier) {
+        if let Some(error) = self.get_item_roles_error(token_id) {
+            sc_panic!(error);
+        }
+    }
+
+    fn get_item_roles_error(&self, token_id: &TokenIdentifier) -> Option<&'static str> {
+        let roles = self.blockchain().get_esdt_local_roles(token_id);
+
+        if roles.has_role(&EsdtLocalRole::NftAddQuantity) == false {
+            return Option::Some(ERR_ADD_QUANTITY_ROLE_NOT_SET_FOR_ITEM);
+        }
+
+        return self.get_item_burn_role_error(token_id);
+    }
+
+    fn require_item_burn_role_set(&self, token_id: &TokenIdentifier) {
+        if let Some(error) = self.get_item_burn_role_error(token_id) {
+            sc_panic!(error);
+        }
+    }
+
+    fn get_item_burn_role_error(&self, token_id: &TokenIdentifier) -> Option<&'static str> {
+        let roles = self.blockchain().get_esdt_local_roles(token_id);
+
+        if roles.has_role(&EsdtLocalRole::NftBurn) == false {
+            return Option::Some(ERR_BURN_ROLE_NOT_SET_FOR_ITEM);
+        }
+
+        return Option::None;
+    }
+
+    /// Make sure that the smart contract can create and burn the equippable, or update it in place.
+    fn require_equippable_collection_roles_set(&self, equippable_token_id: &TokenIdentifier) {
+        if let Some(error) = self.get_equippable_collection_roles_error(equippable_token_id) {
+            sc_panic!(error);
+        }
+    }
+
+    fn get_equippable_collection_roles_error(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> Option<&'static str> {
+        let roles = self.blockchain().get_esdt_local_roles(equippable_token_id);
+
+        if self.update_mode(equippable_token_id).get() == UpdateMode::InPlace {
+            if roles.has_role(&EsdtLocalRole::NftUpdateAttributes) == false {
+                return Option::Some(ERR_UPDATE_ATTRIBUTES_ROLE_NOT_SET_FOR_EQUIPPABLE);
+            }
+
+            if roles.has_role(&EsdtLocalRole::NftAddUri) == false {
+                return Option::Some(ERR_ADD_URI_ROLE_NOT_SET_FOR_EQUIPPABLE);
+            }
+        } else {
+            if roles.has_role(&EsdtLocalRole::NftCreate) == false {
+                return Option::Some(ERR_CREATE_ROLE_NOT_SET_FOR_EQUIPPABLE);
+            }
+
+            if roles.has_role(&EsdtLocalRole::NftBurn) == false {
+                return Option::Some(ERR_BURN_ROLE_NOT_SET_FOR_EQUIPPABLE);
+            }
+        }
+
+        return Option::None;
+    }
+}
+
+fn check<M: ManagedTypeApi>(condition: bool, error: &str) -> Result<(), ManagedBuffer<M>> {
+    if condition {
+        return Result::Ok(());
+    } else {
+        return Result::Err(ManagedBuffer::new_from_bytes(error.as_bytes()));
+    }
+}
+
+fn check_error<M: ManagedTypeApi>(opt_error: Option<&str>) -> Result<(), ManagedBuffer<M>> {
+    match opt_error {
+        Some(error) => return Result::Err(ManagedBuffer::new_from_bytes(error.as_bytes())),
+        None => return Result::Ok(()),
+    }
+}
+
+fn count_of<M: ManagedTypeApi + core::cmp::PartialEq>(
+    tokens: &ManagedVec<M, Token<M>>,
+    token: &Token<M>,
+) -> usize {
+    return tokens.iter().filter(|other| other == token).count();
+}
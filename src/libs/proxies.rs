@@ -0,0 +1,89 @@
+use crate::constants::*;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Contracts (e.g. marketplaces, staking) allowed to customize an equippable on behalf of a user, and to deliver it to the user.
+#[elrond_wasm::module]
+pub trait ProxiesModule:
+    super::customize::CustomizeModule
+    + super::storage::StorageModule
+    + super::equippable_uris::EquippableUrisModule
+    + super::uris::UrisModule
+    + super::treasury::TreasuryModule
+    + super::pause::PauseModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+    + super::customization::CustomizationModule
+    + super::escrow::EscrowModule
+{
+    #[storage_mapper("proxies")]
+    fn proxies(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[endpoint(addProxy)]
+    #[only_owner]
+    fn add_proxy(&self, proxy: ManagedAddress) {
+        self.proxies().insert(proxy);
+    }
+
+    #[endpoint(removeProxy)]
+    #[only_owner]
+    fn remove_proxy(&self, proxy: ManagedAddress) {
+        require!(self.proxies().swap_remove(&proxy), ERR_PROXY_NOT_FOUND);
+    }
+
+    #[view(getProxies)]
+    fn get_proxies(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut proxies = MultiValueEncoded::new();
+
+        for proxy in self.proxies().iter() {
+            proxies.push(proxy);
+        }
+
+        return proxies;
+    }
+
+    /// Same as `customize`, but the customized equippable and the unequipped items are sent to the receiver.
+    /// Anyone can set itself as receiver; only the proxies can set another address.
+    #[payable("*")]
+    #[endpoint(customizeFor)]
+    fn customize_for(
+        &self,
+        receiver: ManagedAddress,
+        to_unequip_slots: MultiValueEncoded<ManagedBuffer<Self::Api>>,
+    ) -> u64 {
+        self.require_not_paused(b"customizeFor");
+        self.require_can_deliver_to(&receiver);
+
+        let payments = self.call_value().all_esdt_transfers();
+
+        require!(payments.len() >= 1, ERR_NEED_EQUIPPABLE);
+
+        let items_payments = payments.slice(1, payments.len()).unwrap_or_default();
+
+        return self.customize_equippable(
+            &payments.get(0),
+            &items_payments,
+            &to_unequip_slots.to_vec(),
+            &receiver,
+            false,
+        );
+    }
+
+    fn require_can_deliver_to(&self, receiver: &ManagedAddress) {
+        require!(
+            receiver.is_zero() == false && receiver != &self.blockchain().get_sc_address(),
+            ERR_INVALID_RECEIVER
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            receiver == &caller || self.proxies().contains(&caller),
+            ERR_NOT_ALLOWED_TO_SET_RECEIVER
+        );
+    }
+}
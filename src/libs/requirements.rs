@@ -0,0 +1,169 @@
+use crate::{
+    constants::*,
+    structs::{
+        equippable_attributes::EquippableAttributes, item::Item, item_requirement::ItemRequirement,
+    },
+};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+#[elrond_wasm::module]
+pub trait RequirementsModule: super::storage::StorageModule + super::slots::SlotsModule {
+    /// Require an item, or any item if `required_name` is not provided, in `required_slot` to equip the item.
+    #[endpoint(addItemRequirement)]
+    #[only_owner]
+    fn add_item_requirement(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        required_slot: ManagedBuffer,
+        opt_required_name: OptionalValue<ManagedBuffer>,
+    ) {
+        let item = Item { name, slot };
+
+        require!(
+            self.has_item(&equippable_token_id, &item),
+            ERR_ITEM_NOT_REGISTERED
+        );
+        require!(
+            item.slot != required_slot,
+            ERR_ITEM_CANNOT_REQUIRE_ITS_OWN_SLOT
+        );
+        self.require_slot_registered(&equippable_token_id, &required_slot);
+
+        self.item_requirements(&equippable_token_id, &item)
+            .insert(ItemRequirement {
+                slot: required_slot,
+                name: opt_required_name.into_option(),
+            });
+    }
+
+    #[endpoint(removeItemRequirement)]
+    #[only_owner]
+    fn remove_item_requirement(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+        required_slot: ManagedBuffer,
+        opt_required_name: OptionalValue<ManagedBuffer>,
+    ) {
+        let requirement = ItemRequirement {
+            slot: required_slot,
+            name: opt_required_name.into_option(),
+        };
+
+        require!(
+            self.item_requirements(&equippable_token_id, &Item { name, slot })
+                .swap_remove(&requirement),
+            ERR_ITEM_REQUIREMENT_NOT_FOUND
+        );
+    }
+
+    #[view(getItemRequirements)]
+    fn get_item_requirements(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer,
+        name: ManagedBuffer,
+    ) -> MultiValueEncoded<ItemRequirement<Self::Api>> {
+        let mut output = MultiValueEncoded::new();
+
+        for requirement in self
+            .item_requirements(&equippable_token_id, &Item { name, slot })
+            .iter()
+        {
+            output.push(requirement);
+        }
+
+        return output;
+    }
+
+    /// Make the items requiring the remapped item require the new item instead.
+    fn rename_required_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        old_item: &Item<Self::Api>,
+        new_item: &Item<Self::Api>,
+    ) {
+        if old_item == new_item {
+            return;
+        }
+
+        let old_requirement = ItemRequirement {
+            slot: old_item.slot.clone(),
+            name: Option::Some(old_item.name.clone()),
+        };
+
+        for item in self.registered_items(equippable_token_id).iter() {
+            if self
+                .item_requirements(equippable_token_id, &item)
+                .swap_remove(&old_requirement)
+            {
+                require!(
+                    item.slot != new_item.slot,
+                    ERR_ITEM_CANNOT_REQUIRE_ITS_OWN_SLOT
+                );
+
+                self.item_requirements(equippable_token_id, &item)
+                    .insert(ItemRequirement {
+                        slot: new_item.slot.clone(),
+                        name: Option::Some(new_item.name.clone()),
+                    });
+            }
+        }
+    }
+
+    /// Make sure that every item equipped has its requirements equipped.
+    /// `previous_attributes` is used to tell apart an unmet requirement from a required item being unequipped.
+    fn require_items_requirements_met(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) {
+        if let Some(error) =
+            self.get_items_requirements_error(equippable_token_id, previous_attributes, attributes)
+        {
+            sc_panic!(error);
+        }
+    }
+
+    fn get_items_requirements_error(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) -> Option<&'static str> {
+        for item in attributes.get_equipped_items().iter() {
+            for requirement in self.item_requirements(equippable_token_id, &item).iter() {
+                if is_requirement_met(attributes, &requirement) {
+                    continue;
+                }
+
+                if is_requirement_met(previous_attributes, &requirement) {
+                    return Option::Some(ERR_CANNOT_UNEQUIP_REQUIRED_ITEM);
+                } else {
+                    return Option::Some(ERR_ITEM_REQUIREMENT_NOT_MET);
+                }
+            }
+        }
+
+        return Option::None;
+    }
+}
+
+fn is_requirement_met<M: ManagedTypeApi>(
+    attributes: &EquippableAttributes<M>,
+    requirement: &ItemRequirement<M>,
+) -> bool {
+    return match attributes.get_name(&requirement.slot) {
+        Some(equipped_name) => match &requirement.name {
+            Some(required_name) => &equipped_name == required_name,
+            None => true,
+        },
+        None => false,
+    };
+}
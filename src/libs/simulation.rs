@@ -0,0 +1,109 @@
+use crate::structs::{
+    customize_simulation::CustomizeSimulation, equippable_attributes::EquippableAttributes,
+    token::Token,
+};
+use elrond_wasm::elrond_codec::TopEncode;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Dry-run of the customize endpoint, for front-ends.
+#[elrond_wasm::module]
+pub trait SimulationModule:
+    super::customize::CustomizeModule
+    + super::storage::StorageModule
+    + super::equippable_uris::EquippableUrisModule
+    + super::uris::UrisModule
+    + super::treasury::TreasuryModule
+    + super::pause::PauseModule
+    + super::slots::SlotsModule
+    + super::requirements::RequirementsModule
+    + super::inventory::InventoryModule
+    + super::fees::FeesModule
+    + super::cooldowns::CooldownsModule
+    + super::preview::PreviewModule
+    + super::customization::CustomizationModule
+    + super::escrow::EscrowModule
+{
+    /// Returns what customizing the equippable held by `owner` would do.
+    /// If the customization would fail, only the error is set.
+    #[view(simulateCustomize)]
+    fn simulate_customize(
+        &self,
+        owner: ManagedAddress,
+        equippable_token_id: TokenIdentifier,
+        equippable_nonce: u64,
+        to_unequip_slots: ManagedVec<ManagedBuffer<Self::Api>>,
+        items: MultiValueEncoded<MultiValue2<TokenIdentifier, u64>>,
+    ) -> CustomizeSimulation<Self::Api> {
+        let mut tokens = ManagedVec::new();
+        for item in items {
+            let (token_id, token_nonce) = item.into_tuple();
+
+            tokens.push(Token::new(token_id, token_nonce));
+        }
+
+        let mut attributes = EquippableAttributes::empty();
+        let mut sent_back = ManagedVec::new();
+
+        let result = match self.get_pause_error(b"customize") {
+            Some(error) => Result::Err(ManagedBuffer::new_from_bytes(error.as_bytes())),
+            None => self.preview_customization(
+                &owner,
+                &owner,
+                &equippable_token_id,
+                equippable_nonce,
+                &to_unequip_slots,
+                &tokens,
+                &mut attributes,
+                &mut sent_back,
+            ),
+        };
+
+        let name = match result {
+            Result::Ok(name) => name,
+            Result::Err(error) => {
+                return CustomizeSimulation {
+                    attributes: ManagedBuffer::new(),
+                    sent_back: ManagedVec::new(),
+                    has_uri: false,
+                    is_held: false,
+                    error,
+                };
+            }
+        };
+
+        let uri_attributes = attributes.without_locks();
+        let has_uri = self
+            .find_uri(&equippable_token_id, &uri_attributes, &name)
+            .is_some();
+        // like customize, hold in escrow before falling back to the placeholder
+        let is_held = has_uri == false && self.escrow_enabled(&equippable_token_id).get();
+        let error = if is_held {
+            match self.get_render_queue_conflict_error(&equippable_token_id, &uri_attributes, &name)
+            {
+                Some(error) => ManagedBuffer::new_from_bytes(error.as_bytes()),
+                None => ManagedBuffer::new(),
+            }
+        } else if has_uri || self.placeholder_uri(&equippable_token_id).is_empty() == false {
+            ManagedBuffer::new()
+        } else {
+            sc_format!(
+                "There is no URI associated to the attributes {} for {}.",
+                uri_attributes,
+                name
+            )
+        };
+
+        let mut encoded_attributes = ManagedBuffer::new();
+        let _ = attributes.top_encode(&mut encoded_attributes);
+
+        return CustomizeSimulation {
+            attributes: encoded_attributes,
+            sent_back,
+            has_uri,
+            is_held,
+            error,
+        };
+    }
+}
@@ -0,0 +1,387 @@
+use core::ops::Deref;
+
+use crate::{
+    constants::*,
+    structs::equippable_attributes::{
+        panic_if_name_contains_unsupported_characters,
+        panic_if_slot_contains_unsupported_characters, EquippableAttributes,
+    },
+};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The slots registry of each equippable collection. While no slot is registered for a collection, every slot is accepted.
+#[elrond_wasm::module]
+pub trait SlotsModule: super::storage::StorageModule {
+    /// The layer index of each slot. The lowest layer is drawn first.
+    #[storage_mapper("slots")]
+    fn slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> MapMapper<ManagedBuffer<Self::Api>, u32>;
+
+    /// Zero means no limit.
+    #[storage_mapper("max_slots_per_equippable")]
+    fn max_slots_per_equippable(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<u32>;
+
+    /// Slots that cannot be equipped at the same time, by group name.
+    /// A slot can belong to several groups (e.g. a suit excludes the top, and the bottom).
+    #[storage_mapper("exclusion_groups")]
+    fn exclusion_groups(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> MapMapper<ManagedBuffer<Self::Api>, ManagedVec<Self::Api, ManagedBuffer<Self::Api>>>;
+
+    /// The item equipped when a mandatory slot is unequipped, by slot.
+    #[storage_mapper("default_items")]
+    fn default_items(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> MapMapper<ManagedBuffer<Self::Api>, ManagedBuffer<Self::Api>>;
+
+    /// Register slots with their layer index. A slot already registered gets its layer updated.
+    #[endpoint(registerSlots)]
+    #[only_owner]
+    fn register_slots(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slots: MultiValueEncoded<MultiValue2<ManagedBuffer<Self::Api>, u32>>,
+    ) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        for kvp in slots {
+            let (slot, layer) = kvp.into_tuple();
+
+            panic_if_slot_contains_unsupported_characters(&slot);
+
+            self.slots(&equippable_token_id).insert(slot, layer);
+        }
+    }
+
+    #[endpoint(unregisterSlot)]
+    #[only_owner]
+    fn unregister_slot(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer<Self::Api>,
+    ) {
+        require!(
+            self.slots(&equippable_token_id).contains_key(&slot),
+            ERR_SLOT_NOT_REGISTERED
+        );
+        require!(
+            self.registered_items(&equippable_token_id)
+                .iter()
+                .any(|item| item.slot == slot)
+                == false,
+            ERR_SLOT_HAS_REGISTERED_ITEMS
+        );
+
+        self.slots(&equippable_token_id).remove(&slot);
+    }
+
+    #[endpoint(setMaxSlotsPerEquippable)]
+    #[only_owner]
+    fn set_max_slots_per_equippable(&self, equippable_token_id: TokenIdentifier, max_slots: u32) {
+        self.require_equippable_collection(&equippable_token_id);
+
+        self.max_slots_per_equippable(&equippable_token_id)
+            .set(max_slots);
+    }
+
+    /// Create or replace an exclusion group. Equipping an item in one of the slots unequips the others.
+    #[endpoint(setExclusionGroup)]
+    #[only_owner]
+    fn set_exclusion_group(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        name: ManagedBuffer<Self::Api>,
+        slots: MultiValueEncoded<ManagedBuffer<Self::Api>>,
+    ) {
+        let slots = slots.to_vec();
+
+        require!(slots.len() >= 2, ERR_EXCLUSION_GROUP_NEEDS_TWO_SLOTS);
+
+        self.require_equippable_collection(&equippable_token_id);
+
+        for slot in slots.iter() {
+            self.require_slot_registered(&equippable_token_id, &slot);
+        }
+
+        self.exclusion_groups(&equippable_token_id)
+            .insert(name, slots);
+    }
+
+    #[endpoint(removeExclusionGroup)]
+    #[only_owner]
+    fn remove_exclusion_group(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        name: ManagedBuffer<Self::Api>,
+    ) {
+        require!(
+            self.exclusion_groups(&equippable_token_id)
+                .remove(&name)
+                .is_some(),
+            ERR_EXCLUSION_GROUP_NOT_FOUND
+        );
+    }
+
+    #[view(getExclusionGroups)]
+    fn get_exclusion_groups(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<
+        MultiValue2<ManagedBuffer<Self::Api>, ManagedVec<Self::Api, ManagedBuffer<Self::Api>>>,
+    > {
+        let mut output = MultiValueEncoded::new();
+
+        for (name, slots) in self.exclusion_groups(&equippable_token_id).iter() {
+            output.push(MultiValue2::from((name, slots)));
+        }
+
+        return output;
+    }
+
+    /// Returns the slots sharing an exclusion group with the slot provided.
+    fn get_conflicting_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        slot: &ManagedBuffer<Self::Api>,
+    ) -> ManagedVec<Self::Api, ManagedBuffer<Self::Api>> {
+        let mut output = ManagedVec::<Self::Api, ManagedBuffer<Self::Api>>::new();
+
+        for slots in self.exclusion_groups(equippable_token_id).values() {
+            if slots.contains(slot) == false {
+                continue;
+            }
+
+            for other_slot in slots.iter() {
+                if other_slot.deref() != slot && output.contains(&other_slot) == false {
+                    output.push(other_slot.deref().clone());
+                }
+            }
+        }
+
+        return output;
+    }
+
+    /// Make the slot mandatory. Unequipping it reverts to the default item, which is never sent to the user.
+    #[endpoint(setSlotDefault)]
+    #[only_owner]
+    fn set_slot_default(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer<Self::Api>,
+        name: ManagedBuffer<Self::Api>,
+    ) {
+        self.require_equippable_collection(&equippable_token_id);
+        panic_if_name_contains_unsupported_characters(&Option::Some(name.clone()));
+        self.require_slot_registered(&equippable_token_id, &slot);
+
+        self.default_items(&equippable_token_id).insert(slot, name);
+    }
+
+    #[endpoint(removeSlotDefault)]
+    #[only_owner]
+    fn remove_slot_default(
+        &self,
+        equippable_token_id: TokenIdentifier,
+        slot: ManagedBuffer<Self::Api>,
+    ) {
+        require!(
+            self.default_items(&equippable_token_id)
+                .remove(&slot)
+                .is_some(),
+            ERR_SLOT_IS_NOT_MANDATORY
+        );
+    }
+
+    #[view(getSlotsDefaults)]
+    fn get_slots_defaults(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer<Self::Api>, ManagedBuffer<Self::Api>>> {
+        let mut output = MultiValueEncoded::new();
+
+        for (slot, name) in self.default_items(&equippable_token_id).iter() {
+            output.push(MultiValue2::from((slot, name)));
+        }
+
+        return output;
+    }
+
+    fn is_default_item_equipped(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        slot: &ManagedBuffer<Self::Api>,
+    ) -> bool {
+        return match self.default_items(equippable_token_id).get(slot) {
+            Some(default_name) => attributes.get_name(slot) == Option::Some(default_name),
+            None => false,
+        };
+    }
+
+    /// Returns the slots with their layer index, sorted by layer.
+    #[view(getSlots)]
+    fn get_slots(
+        &self,
+        equippable_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer<Self::Api>, u32>> {
+        let slots = self.slots(&equippable_token_id);
+
+        let mut remaining_slots = ManagedVec::<Self::Api, ManagedBuffer<Self::Api>>::new();
+        for slot in slots.keys() {
+            remaining_slots.push(slot);
+        }
+
+        let mut output = MultiValueEncoded::new();
+
+        while remaining_slots.len() > 0 {
+            let mut lowest_index = 0;
+            let mut lowest_layer = slots.get(&remaining_slots.get(0)).unwrap();
+
+            for (index, slot) in remaining_slots.iter().enumerate() {
+                let layer = slots.get(&slot).unwrap();
+
+                if layer < lowest_layer {
+                    lowest_index = index;
+                    lowest_layer = layer;
+                }
+            }
+
+            let lowest_slot = remaining_slots.get(lowest_index).deref().clone();
+            output.push(MultiValue2::from((lowest_slot, lowest_layer)));
+            remaining_slots.remove(lowest_index);
+        }
+
+        return output;
+    }
+
+    fn require_slot_registered(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        slot: &ManagedBuffer<Self::Api>,
+    ) {
+        require!(
+            self.is_slot_accepted(equippable_token_id, slot),
+            "The slot {} is not registered.",
+            slot
+        );
+    }
+
+    fn is_slot_accepted(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        slot: &ManagedBuffer<Self::Api>,
+    ) -> bool {
+        let slots = self.slots(equippable_token_id);
+
+        return slots.is_empty() || slots.contains_key(slot);
+    }
+
+    /// Make sure that the attributes only use registered slots, and do not exceed the maximum of items equipped.
+    fn require_attributes_match_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) {
+        if let Result::Err(error) =
+            self.check_attributes_match_slots(equippable_token_id, attributes)
+        {
+            sc_panic!(error);
+        }
+    }
+
+    fn check_attributes_match_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) -> Result<(), ManagedBuffer<Self::Api>> {
+        for slot in attributes.get_slots().iter() {
+            if self.is_slot_accepted(equippable_token_id, &slot) == false {
+                return Result::Err(sc_format!("The slot {} is not registered.", slot.deref()));
+            }
+        }
+
+        if self.is_below_max_slots(equippable_token_id, attributes) == false {
+            return Result::Err(ManagedBuffer::new_from_bytes(
+                ERR_TOO_MANY_SLOTS_EQUIPPED.as_bytes(),
+            ));
+        }
+
+        return Result::Ok(());
+    }
+
+    /// Make sure that a customization only equips items in registered slots, and does not exceed the maximum of items equipped.
+    /// The items equipped before their slot was unregistered, or before the maximum was lowered, are kept and can always be unequipped.
+    fn require_customized_attributes_match_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) {
+        if let Result::Err(error) = self.check_customized_attributes_match_slots(
+            equippable_token_id,
+            previous_attributes,
+            attributes,
+        ) {
+            sc_panic!(error);
+        }
+    }
+
+    fn check_customized_attributes_match_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        previous_attributes: &EquippableAttributes<Self::Api>,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) -> Result<(), ManagedBuffer<Self::Api>> {
+        let equipped_items = attributes.get_equipped_items();
+
+        for item in equipped_items.iter() {
+            if self.is_slot_accepted(equippable_token_id, &item.slot) == false
+                && previous_attributes.get_name(&item.slot) != Option::Some(item.name.clone())
+            {
+                return Result::Err(sc_format!("The slot {} is not registered.", item.slot));
+            }
+        }
+
+        if self.is_below_max_slots(equippable_token_id, attributes) == false
+            && equipped_items.len() > previous_attributes.get_equipped_items().len()
+        {
+            return Result::Err(ManagedBuffer::new_from_bytes(
+                ERR_TOO_MANY_SLOTS_EQUIPPED.as_bytes(),
+            ));
+        }
+
+        return Result::Ok(());
+    }
+
+    fn do_attributes_match_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) -> bool {
+        let all_slots_accepted = attributes
+            .get_slots()
+            .iter()
+            .all(|slot| self.is_slot_accepted(equippable_token_id, &slot));
+
+        return all_slots_accepted && self.is_below_max_slots(equippable_token_id, attributes);
+    }
+
+    fn is_below_max_slots(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) -> bool {
+        let max_slots = self.max_slots_per_equippable(equippable_token_id).get();
+
+        return max_slots == 0 || attributes.get_equipped_items().len() <= max_slots as usize;
+    }
+}
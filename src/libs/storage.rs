@@ -1,6 +1,9 @@
 use crate::{
-    structs::{item::Item, token::Token},
-    utils::bidimapper_utils::ContainsUtils,
+    constants::ERR_UNKNOWN_EQUIPPABLE_COLLECTION,
+    structs::{
+        item::Item, item_requirement::ItemRequirement, registry_change::RegistryChange,
+        supply_mode::SupplyMode, token::Token, update_mode::UpdateMode,
+    },
 };
 
 elrond_wasm::imports!();
@@ -8,43 +11,244 @@ elrond_wasm::derive_imports!();
 
 #[elrond_wasm::module]
 pub trait StorageModule {
-    #[storage_mapper("equippable_token_id")]
-    fn equippable_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    /// The collections of equippables managed by the contract.
+    #[storage_mapper("equippable_token_ids")]
+    fn equippable_token_ids(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Remint by default.
+    #[storage_mapper("update_mode")]
+    fn update_mode(&self, equippable_token_id: &TokenIdentifier) -> SingleValueMapper<UpdateMode>;
 
     #[storage_mapper("royalties_overrided")]
     fn royalties_overrided(&self) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("registered_items")]
+    fn registered_items(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> UnorderedSetMapper<Item<Self::Api>>;
+
+    #[storage_mapper("item_of_token")]
+    fn item_of_token(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        token: &Token<Self::Api>,
+    ) -> SingleValueMapper<Item<Self::Api>>;
+
+    #[storage_mapper("tokens_of_item")]
+    fn tokens_of_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> UnorderedSetMapper<Token<Self::Api>>;
+
+    /// The registry of the single collection managed before an item could be represented by several tokens.
+    /// Moved to the registry of a collection by `migrateLegacyItems`.
     #[storage_mapper("mapper_items_token")]
-    fn map_items_tokens(&self) -> BiDiMapper<Self::Api, Item<Self::Api>, Token<Self::Api>>;
+    fn legacy_items_tokens(&self) -> BiDiMapper<Self::Api, Item<Self::Api>, Token<Self::Api>>;
+
+    /// The token sent back to the user when the item is unequipped.
+    #[storage_mapper("preferred_token_of_item")]
+    fn preferred_token_of_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> SingleValueMapper<Token<Self::Api>>;
+
+    /// The preferred token of items removed from the registry while equippables may still carry them.
+    /// Unequipping such an item always empties the slot; the token is sent back only if it can still be supplied.
+    #[storage_mapper("unregistered_items")]
+    fn unregistered_items(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> SingleValueMapper<Token<Self::Api>>;
+
+    /// Items whose name has been remapped; equippables still carrying the old name are migrated on customization.
+    #[storage_mapper("remapped_items")]
+    fn remapped_items(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        old_item: &Item<Self::Api>,
+    ) -> SingleValueMapper<Item<Self::Api>>;
+
+    /// Custody by default.
+    #[storage_mapper("supply_mode")]
+    fn supply_mode(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> SingleValueMapper<SupplyMode>;
+
+    /// Consumable items are burned on equip, and never sent back to the users.
+    #[storage_mapper("consumable_items")]
+    fn consumable_items(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> UnorderedSetMapper<Item<Self::Api>>;
+
+    /// Seconds during which the item cannot be unequipped once equipped. PERMANENT_LOCK makes it soulbound.
+    #[storage_mapper("item_lock_duration")]
+    fn item_lock_duration(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("item_requirements")]
+    fn item_requirements(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> UnorderedSetMapper<ItemRequirement<Self::Api>>;
+
+    #[storage_mapper("registry_changes")]
+    fn registry_changes(&self) -> VecMapper<RegistryChange<Self::Api>>;
 
     #[storage_mapper("authorized_addresses_to_set_uris")]
     fn authorized_addresses_to_set_uris(&self) -> UnorderedSetMapper<ManagedAddress<Self::Api>>;
 
-    fn has_item(&self, item: &Item<Self::Api>) -> bool {
-        return self
-            .map_items_tokens()
-            .contains_id(item, b"mapper_items_token");
+    fn require_equippable_collection(&self, equippable_token_id: &TokenIdentifier) {
+        require!(
+            self.equippable_token_ids().contains(equippable_token_id),
+            ERR_UNKNOWN_EQUIPPABLE_COLLECTION
+        );
+    }
+
+    fn has_item(&self, equippable_token_id: &TokenIdentifier, item: &Item<Self::Api>) -> bool {
+        return self.registered_items(equippable_token_id).contains(item);
+    }
+
+    fn has_token(&self, equippable_token_id: &TokenIdentifier, token: &Token<Self::Api>) -> bool {
+        return self.item_of_token(equippable_token_id, token).is_empty() == false;
     }
 
-    fn has_token(&self, token: &Token<Self::Api>) -> bool {
+    /// Returns true if the token represents an item in any collection.
+    fn is_item_token(&self, token: &Token<Self::Api>) -> bool {
         return self
-            .map_items_tokens()
-            .contains_value(token, b"mapper_items_token");
+            .equippable_token_ids()
+            .iter()
+            .any(|equippable_token_id| self.has_token(&equippable_token_id, token));
+    }
+
+    /// Returns true if any token of this identifier represents an item in any collection.
+    fn is_item_token_id(&self, token_id: &TokenIdentifier) -> bool {
+        for equippable_token_id in self.equippable_token_ids().iter() {
+            for item in self.registered_items(&equippable_token_id).iter() {
+                for token in self.tokens_of_item(&equippable_token_id, &item).iter() {
+                    if &token.token == token_id {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        return false;
     }
 
-    fn get_item(&self, token: &Token<Self::Api>) -> Option<Item<Self::Api>> {
-        if self.has_token(token) == false {
+    fn get_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        token: &Token<Self::Api>,
+    ) -> Option<Item<Self::Api>> {
+        if self.has_token(equippable_token_id, token) == false {
             return None;
         } else {
-            return Some(self.map_items_tokens().get_id(token));
+            return Some(self.item_of_token(equippable_token_id, token).get());
         }
     }
 
-    fn get_token(&self, item: &Item<Self::Api>) -> Option<Token<Self::Api>> {
-        if self.has_item(item) == false {
+    /// Returns the preferred token of the item.
+    fn get_token(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> Option<Token<Self::Api>> {
+        if self.has_item(equippable_token_id, item) == false {
             return None;
         } else {
-            return Some(self.map_items_tokens().get_value(item));
+            return Some(
+                self.preferred_token_of_item(equippable_token_id, item)
+                    .get(),
+            );
         }
     }
+
+    /// Link a token to an item. The first token linked to an item becomes its preferred token.
+    /// Returns false if the token already represents an item.
+    fn insert_item_token(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+        token: &Token<Self::Api>,
+    ) -> bool {
+        if self.has_token(equippable_token_id, token) {
+            return false;
+        }
+
+        self.item_of_token(equippable_token_id, token).set(item);
+        self.tokens_of_item(equippable_token_id, item)
+            .insert(token.clone());
+
+        if self
+            .registered_items(equippable_token_id)
+            .insert(item.clone())
+        {
+            // forget a previous registration of the item
+            self.clear_item_config(equippable_token_id, item);
+
+            self.preferred_token_of_item(equippable_token_id, item)
+                .set(token);
+        }
+
+        return true;
+    }
+
+    /// Remove the item and every token linked to it.
+    /// The preferred token and the configuration of the item are kept, so that the equippables carrying it can still unequip it.
+    fn remove_item(&self, equippable_token_id: &TokenIdentifier, item: &Item<Self::Api>) {
+        for token in self.tokens_of_item(equippable_token_id, item).iter() {
+            self.item_of_token(equippable_token_id, &token).clear();
+        }
+
+        self.unregistered_items(equippable_token_id, item).set(
+            self.preferred_token_of_item(equippable_token_id, item)
+                .get(),
+        );
+
+        self.tokens_of_item(equippable_token_id, item).clear();
+        self.preferred_token_of_item(equippable_token_id, item)
+            .clear();
+        self.registered_items(equippable_token_id).swap_remove(item);
+    }
+
+    /// Forget the configuration of an item removed from the registry.
+    fn clear_item_config(&self, equippable_token_id: &TokenIdentifier, item: &Item<Self::Api>) {
+        self.unregistered_items(equippable_token_id, item).clear();
+        self.supply_mode(equippable_token_id, item).clear();
+        self.consumable_items(equippable_token_id).swap_remove(item);
+        self.item_lock_duration(equippable_token_id, item).clear();
+        self.item_requirements(equippable_token_id, item).clear();
+    }
+
+    /// Follow the remapping chain of an item, and return the item currently registered.
+    fn resolve_remapped_item(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        item: &Item<Self::Api>,
+    ) -> Item<Self::Api> {
+        let mut resolved_item = item.clone();
+
+        while self
+            .remapped_items(equippable_token_id, &resolved_item)
+            .is_empty()
+            == false
+        {
+            resolved_item = self
+                .remapped_items(equippable_token_id, &resolved_item)
+                .get();
+        }
+
+        return resolved_item;
+    }
 }
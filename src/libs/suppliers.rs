@@ -0,0 +1,168 @@
+use crate::{constants::*, structs::token::Token};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Partners allowed to fill the stock of some items, and to withdraw what they deposited and has not been used.
+#[elrond_wasm::module]
+pub trait SuppliersModule:
+    super::storage::StorageModule + super::inventory::InventoryModule
+{
+    #[storage_mapper("suppliers")]
+    fn suppliers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("supplier_tokens")]
+    fn supplier_tokens(&self, supplier: &ManagedAddress) -> UnorderedSetMapper<Token<Self::Api>>;
+
+    /// Quantity deposited by the supplier, minus the quantity withdrawn.
+    #[storage_mapper("supplier_deposits")]
+    fn supplier_deposits(
+        &self,
+        supplier: &ManagedAddress,
+        token: &Token<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[endpoint(addSupplier)]
+    #[only_owner]
+    fn add_supplier(&self, supplier: ManagedAddress) {
+        self.suppliers().insert(supplier);
+    }
+
+    /// The supplier cannot fill anymore, but can still withdraw its deposits.
+    #[endpoint(removeSupplier)]
+    #[only_owner]
+    fn remove_supplier(&self, supplier: ManagedAddress) {
+        require!(
+            self.suppliers().swap_remove(&supplier),
+            ERR_SUPPLIER_NOT_FOUND
+        );
+    }
+
+    #[endpoint(assignTokensToSupplier)]
+    #[only_owner]
+    fn assign_tokens_to_supplier(
+        &self,
+        supplier: ManagedAddress,
+        tokens: MultiValueEncoded<MultiValue2<TokenIdentifier, u64>>,
+    ) {
+        require!(self.suppliers().contains(&supplier), ERR_SUPPLIER_NOT_FOUND);
+
+        for token in tokens {
+            let (token_id, token_nonce) = token.into_tuple();
+            let token = Token::new(token_id, token_nonce);
+
+            require!(
+                self.is_item_token(&token),
+                ERR_CANNOT_FILL_UNREGISTERED_ITEM
+            );
+
+            self.supplier_tokens(&supplier).insert(token);
+        }
+    }
+
+    #[endpoint(unassignTokensFromSupplier)]
+    #[only_owner]
+    fn unassign_tokens_from_supplier(
+        &self,
+        supplier: ManagedAddress,
+        tokens: MultiValueEncoded<MultiValue2<TokenIdentifier, u64>>,
+    ) {
+        for token in tokens {
+            let (token_id, token_nonce) = token.into_tuple();
+
+            self.supplier_tokens(&supplier)
+                .swap_remove(&Token::new(token_id, token_nonce));
+        }
+    }
+
+    /// Send back to the supplier the tokens it deposited. Only the tokens that are not equipped can be withdrawn.
+    #[endpoint(withdrawSupplierItems)]
+    fn withdraw_supplier_items(
+        &self,
+        items: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+
+        for item in items {
+            let (token_id, token_nonce, amount) = item.into_tuple();
+            let token = Token::new(token_id, token_nonce);
+
+            require!(
+                amount <= self.get_withdrawable_deposits(&caller, &token),
+                ERR_NOT_ENOUGH_SUPPLIER_DEPOSITS
+            );
+
+            self.supplier_deposits(&caller, &token)
+                .update(|deposits| *deposits -= &amount);
+            self.reserved_stock(&token)
+                .update(|reserved| *reserved -= &amount);
+            self.record_withdrawal(&token, &amount);
+
+            self.send()
+                .direct_esdt(&caller, &token.token, token.nonce, &amount, &[]);
+        }
+    }
+
+    #[view(getSuppliers)]
+    fn get_suppliers(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut output = MultiValueEncoded::new();
+
+        for supplier in self.suppliers().iter() {
+            output.push(supplier);
+        }
+
+        return output;
+    }
+
+    /// Returns the tokens assigned to the supplier, with the quantity it can withdraw.
+    #[view(getSupplierTokens)]
+    fn get_supplier_tokens(
+        &self,
+        supplier: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        for token in self.supplier_tokens(&supplier).iter() {
+            let deposits = self.get_withdrawable_deposits(&supplier, &token);
+
+            output.push(MultiValue3::from((token.token, token.nonce, deposits)));
+        }
+
+        return output;
+    }
+
+    /// The stock paid out to users is not tracked per supplier, so the deposits are capped by the available stock.
+    fn get_withdrawable_deposits(
+        &self,
+        supplier: &ManagedAddress,
+        token: &Token<Self::Api>,
+    ) -> BigUint {
+        let deposits = self.supplier_deposits(supplier, token).get();
+        let available = self.get_inventory_of(token).get_available();
+
+        return core::cmp::min(deposits, available);
+    }
+
+    fn require_can_fill(&self, caller: &ManagedAddress, token: &Token<Self::Api>) {
+        if caller == &self.blockchain().get_owner_address() {
+            return;
+        }
+
+        require!(self.suppliers().contains(caller), ERR_NOT_A_SUPPLIER);
+        require!(
+            self.supplier_tokens(caller).contains(token),
+            ERR_TOKEN_NOT_ASSIGNED_TO_SUPPLIER
+        );
+    }
+
+    fn record_supply(&self, supplier: &ManagedAddress, token: &Token<Self::Api>, amount: &BigUint) {
+        if supplier == &self.blockchain().get_owner_address() {
+            return;
+        }
+
+        self.supplier_deposits(supplier, token)
+            .update(|deposits| *deposits += amount);
+        self.reserved_stock(token)
+            .update(|reserved| *reserved += amount);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::constants::*;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Tokens accepted to pay `renderImage`, and the payments received for each token.
+#[elrond_wasm::module]
+pub trait TreasuryModule: super::storage::StorageModule {
+    /// If empty, only `ENQUEUE_PRICE` EGLD is accepted.
+    #[storage_mapper("render_prices")]
+    fn render_prices(&self) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, BigUint>;
+
+    #[storage_mapper("treasury_tokens")]
+    fn treasury_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("treasury")]
+    fn treasury(&self, token: &EgldOrEsdtTokenIdentifier<Self::Api>) -> SingleValueMapper<BigUint>;
+
+    /// Accept a token to pay `renderImage`, or update its price.
+    #[endpoint(setRenderPrice)]
+    #[only_owner]
+    fn set_render_price(&self, token: EgldOrEsdtTokenIdentifier, price: BigUint) {
+        require!(
+            token.is_egld() || token.is_valid_esdt_identifier(),
+            ERR_INVALID_PAYMENT_TOKEN
+        );
+
+        self.render_prices().insert(token, price);
+    }
+
+    #[endpoint(removeRenderPrice)]
+    #[only_owner]
+    fn remove_render_price(&self, token: EgldOrEsdtTokenIdentifier) {
+        require!(
+            self.render_prices().remove(&token).is_some(),
+            ERR_RENDER_PRICE_NOT_FOUND
+        );
+    }
+
+    #[view(getRenderPrices)]
+    fn get_render_prices(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        if self.render_prices().is_empty() {
+            output.push(MultiValue2::from((
+                EgldOrEsdtTokenIdentifier::egld(),
+                BigUint::from(ENQUEUE_PRICE),
+            )));
+        }
+
+        for (token, price) in self.render_prices().iter() {
+            output.push(MultiValue2::from((token, price)));
+        }
+
+        return output;
+    }
+
+    /// Send to the owner the payments received in this token.
+    #[endpoint(claimTreasury)]
+    #[only_owner]
+    fn claim_treasury(&self, token: EgldOrEsdtTokenIdentifier) {
+        let balance = self.treasury(&token).get();
+
+        require!(balance > 0, ERR_TREASURY_EMPTY);
+
+        self.treasury(&token).clear();
+        self.treasury_tokens().swap_remove(&token);
+
+        self.send().direct(
+            &self.blockchain().get_owner_address(),
+            &token,
+            0,
+            &balance,
+            &[],
+        );
+    }
+
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        for token in self.treasury_tokens().iter() {
+            let balance = self.treasury(&token).get();
+
+            output.push(MultiValue2::from((token, balance)));
+        }
+
+        return output;
+    }
+
+    fn receive_render_payment(&self) {
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+
+        if self.render_prices().is_empty() {
+            require!(
+                token.is_egld() && amount == BigUint::from(ENQUEUE_PRICE),
+                ERR_PAY_0001_EGLD
+            );
+        } else {
+            require!(
+                self.render_prices().get(&token) == Option::Some(amount.clone()),
+                ERR_WRONG_RENDER_PAYMENT
+            );
+        }
+
+        self.add_to_treasury(&token, &amount);
+    }
+
+    fn add_to_treasury(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if amount == &0 {
+            return;
+        }
+
+        self.treasury(token).update(|balance| *balance += amount);
+        self.treasury_tokens().insert(token.clone());
+    }
+}
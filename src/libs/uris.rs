@@ -0,0 +1,216 @@
+use crate::{
+    structs::{equippable_attributes::EquippableAttributes, generated_uri::GeneratedUri},
+    utils::managed_buffer_utils::ManagedBufferUtils,
+};
+use elrond_wasm::elrond_codec::TopEncode;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The images rendered for the attributes, the render queue, and the URIs given to the customized equippables.
+/// The endpoints managing them are in EquippableUrisModule.
+#[elrond_wasm::module]
+pub trait UrisModule: super::storage::StorageModule {
+    #[storage_mapper("attributes_to_render_by_name")]
+    fn attributes_to_render_by_name(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> MapMapper<ManagedBuffer<Self::Api>, EquippableAttributes<Self::Api>>;
+
+    #[storage_mapper("uris_of_attributes")]
+    fn uris_of_attributes(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    /// The collection managed before several collections were supported.
+    #[storage_mapper("equippable_token_id")]
+    fn legacy_equippable_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// The render queue of the legacy collection. Moved to the queue of the collection by `migrateLegacyRenderQueue`.
+    #[storage_mapper("attributes_to_render_by_name")]
+    fn legacy_attributes_to_render_by_name(
+        &self,
+    ) -> MapMapper<ManagedBuffer<Self::Api>, EquippableAttributes<Self::Api>>;
+
+    /// The URIs rendered for the legacy collection, still read as a fallback.
+    #[storage_mapper("uris_of_attributes")]
+    fn legacy_uris_of_attributes(
+        &self,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Attributes of equippables showing the placeholder, waiting for the render already queued for the same name.
+    /// The next one is enqueued once that render is done.
+    #[storage_mapper("renders_waiting_by_name")]
+    fn renders_waiting_by_name(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> UnorderedSetMapper<EquippableAttributes<Self::Api>>;
+
+    /// Image of the equippables customized into attributes not rendered yet. Customizing them fails if empty.
+    #[storage_mapper("placeholder_uri")]
+    fn placeholder_uri(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Equippables whose image is the placeholder.
+    #[storage_mapper("placeholder_nonces")]
+    fn placeholder_nonces(&self, equippable_token_id: &TokenIdentifier) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("generated_uris")]
+    fn generated_uris(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+    ) -> UnorderedSetMapper<GeneratedUri<Self::Api>>;
+
+    #[view(getUriOf)]
+    fn get_uri_of(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> ManagedBuffer<Self::Api> {
+        match self.find_uri(equippable_token_id, attributes, name) {
+            Some(uri) => return uri,
+            None => sc_panic!(
+                "There is no URI associated to the attributes {} for {}.",
+                attributes,
+                name
+            ),
+        }
+    }
+
+    /// Returns the URI of the attributes, if rendered.
+    /// The URIs rendered before several collections were supported are found for the legacy collection.
+    fn find_uri(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> Option<ManagedBuffer<Self::Api>> {
+        let uri = self.uris_of_attributes(equippable_token_id, attributes, name);
+        if uri.is_empty() == false {
+            return Option::Some(uri.get());
+        }
+
+        let legacy_uri = self.legacy_uris_of_attributes(attributes, name);
+        if legacy_uri.is_empty() == false
+            && self.legacy_equippable_token_id().get() == *equippable_token_id
+        {
+            return Option::Some(legacy_uri.get());
+        }
+
+        return Option::None;
+    }
+
+    /// Returns the image of the attributes.
+    /// If they are not rendered yet, they are enqueued to render, and the placeholder is returned instead.
+    /// If other attributes are already enqueued for the same name, e.g. with `renderImage`, they are kept, and these ones wait for them to be rendered.
+    fn get_thumbnail_uri(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes: &EquippableAttributes<Self::Api>,
+        name: &ManagedBuffer<Self::Api>,
+    ) -> ManagedBuffer<Self::Api> {
+        let uri_attributes = attributes.without_locks();
+
+        if self
+            .find_uri(equippable_token_id, &uri_attributes, name)
+            .is_some()
+            || self.placeholder_uri(equippable_token_id).is_empty()
+        {
+            return self.get_uri_of(equippable_token_id, &uri_attributes, name);
+        }
+
+        match self
+            .attributes_to_render_by_name(equippable_token_id)
+            .get(name)
+        {
+            Option::None => {
+                self.attributes_to_render_by_name(equippable_token_id)
+                    .insert(name.clone(), uri_attributes);
+            }
+            Option::Some(queued_attributes) if queued_attributes != uri_attributes => {
+                self.renders_waiting_by_name(equippable_token_id, name)
+                    .insert(uri_attributes);
+            }
+            Option::Some(_) => {}
+        }
+
+        return self.placeholder_uri(equippable_token_id).get();
+    }
+
+    /// Enqueue the next attributes waiting for the name, skipping the ones rendered meanwhile.
+    fn enqueue_next_waiting_render(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        name: &ManagedBuffer<Self::Api>,
+    ) {
+        let mut waiting = self.renders_waiting_by_name(equippable_token_id, name);
+
+        while let Some(attributes) = waiting.iter().next() {
+            waiting.swap_remove(&attributes);
+
+            if self
+                .find_uri(equippable_token_id, &attributes, name)
+                .is_none()
+            {
+                self.attributes_to_render_by_name(equippable_token_id)
+                    .insert(name.clone(), attributes);
+                return;
+            }
+        }
+    }
+
+    /// The SHA-256 of the attributes, without the locks.
+    fn get_attributes_hash(
+        &self,
+        attributes: &EquippableAttributes<Self::Api>,
+    ) -> ManagedBuffer<Self::Api> {
+        let mut encoded_attributes = ManagedBuffer::new();
+        let _ = attributes
+            .without_locks()
+            .top_encode(&mut encoded_attributes);
+
+        return self
+            .crypto()
+            .sha256(&encoded_attributes)
+            .as_managed_buffer()
+            .clone();
+    }
+
+    fn build_generated_uris(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        attributes_hash: &ManagedBuffer<Self::Api>,
+    ) -> ManagedVec<ManagedBuffer<Self::Api>> {
+        let mut output = ManagedVec::new();
+
+        for generated_uri in self.generated_uris(equippable_token_id).iter() {
+            let mut uri = generated_uri.prefix.clone();
+            uri.append_hex(attributes_hash);
+            uri.append(&generated_uri.suffix);
+
+            output.push(uri);
+        }
+
+        return output;
+    }
+
+    fn is_generated_uri(
+        &self,
+        equippable_token_id: &TokenIdentifier,
+        uri: &ManagedBuffer<Self::Api>,
+    ) -> bool {
+        return self
+            .generated_uris(equippable_token_id)
+            .iter()
+            .any(|generated_uri| uri.starts_with(&generated_uri.prefix));
+    }
+}
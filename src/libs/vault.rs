@@ -0,0 +1,85 @@
+use crate::{constants::*, structs::token::Token};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Items deposited by the users, to be equipped later by a loadout. They are not part of the stock.
+#[elrond_wasm::module]
+pub trait VaultModule: super::storage::StorageModule + super::inventory::InventoryModule {
+    #[storage_mapper("vault_tokens")]
+    fn vault_tokens(&self, owner: &ManagedAddress) -> UnorderedSetMapper<Token<Self::Api>>;
+
+    #[storage_mapper("vault_balance")]
+    fn vault_balance(
+        &self,
+        owner: &ManagedAddress,
+        token: &Token<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[payable("*")]
+    #[endpoint(depositToVault)]
+    fn deposit_to_vault(&self) {
+        let caller = self.blockchain().get_caller();
+
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            let token = Token::new(payment.token_identifier, payment.token_nonce);
+
+            require!(self.is_item_token(&token), ERR_VAULT_ACCEPTS_ONLY_ITEMS);
+
+            self.vault_balance(&caller, &token)
+                .update(|balance| *balance += &payment.amount);
+            self.vault_stock(&token)
+                .update(|stock| *stock += &payment.amount);
+            self.vault_tokens(&caller).insert(token);
+        }
+    }
+
+    #[endpoint(withdrawFromVault)]
+    fn withdraw_from_vault(
+        &self,
+        items: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+
+        for item in items {
+            let (token_id, token_nonce, amount) = item.into_tuple();
+            let token = Token::new(token_id, token_nonce);
+
+            self.take_from_vault(&caller, &token, &amount);
+
+            self.send()
+                .direct_esdt(&caller, &token.token, token.nonce, &amount, &[]);
+        }
+    }
+
+    #[view(getVault)]
+    fn get_vault(
+        &self,
+        owner: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>> {
+        let mut output = MultiValueEncoded::new();
+
+        for token in self.vault_tokens(&owner).iter() {
+            let balance = self.vault_balance(&owner, &token).get();
+
+            output.push(MultiValue3::from((token.token, token.nonce, balance)));
+        }
+
+        return output;
+    }
+
+    fn take_from_vault(&self, owner: &ManagedAddress, token: &Token<Self::Api>, amount: &BigUint) {
+        let balance = self.vault_balance(owner, token).get();
+
+        require!(amount <= &balance, ERR_NOT_ENOUGH_IN_VAULT);
+
+        self.vault_stock(token).update(|stock| *stock -= amount);
+
+        if amount == &balance {
+            self.vault_balance(owner, token).clear();
+            self.vault_tokens(owner).swap_remove(token);
+        } else {
+            self.vault_balance(owner, token).set(balance - amount);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use super::token::Token;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The outcome of a customization, as returned by `simulateCustomize`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct CustomizeSimulation<M: ManagedTypeApi> {
+    /// The attributes of the new equippable, encoded like on-chain attributes.
+    pub attributes: ManagedBuffer<M>,
+    /// The tokens that would be sent back to the owner.
+    pub sent_back: ManagedVec<M, Token<M>>,
+    /// True if the image of the new attributes has already been rendered.
+    pub has_uri: bool,
+    /// True if the equippable would be held in escrow until the image is rendered.
+    pub is_held: bool,
+    /// The error the customize endpoint would raise, empty if it would succeed.
+    pub error: ManagedBuffer<M>,
+}
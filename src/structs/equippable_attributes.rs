@@ -1,5 +1,6 @@
 use crate::{
-    constants::UNEQUIPPED_ITEM_NAME,
+    constants::{PERMANENT_LOCK, PERMANENT_LOCK_NAME, UNEQUIPPED_ITEM_NAME},
+    structs::item::Item,
     utils::{managed_buffer_utils::ManagedBufferUtils, managed_vec_utils::EqUtils},
 };
 use core::ops::Deref;
@@ -19,6 +20,8 @@ elrond_wasm::derive_imports!();
 struct EquippableAttribute<M: ManagedTypeApi> {
     pub slot: ManagedBuffer<M>,
     pub name: Option<ManagedBuffer<M>>,
+    /// Zero if the slot is not locked.
+    pub unlock_timestamp: u64,
 }
 
 impl<M: ManagedTypeApi> EquippableAttribute<M> {
@@ -35,16 +38,39 @@ impl<M: ManagedTypeApi> EquippableAttribute<M> {
         output.append_bytes(b":");
         output.append(&item_name);
 
+        if self.unlock_timestamp == PERMANENT_LOCK {
+            output.append_bytes(b":");
+            output.append_bytes(PERMANENT_LOCK_NAME);
+        } else if self.unlock_timestamp > 0 {
+            output.append_bytes(b":");
+            output.append_u64(self.unlock_timestamp);
+        }
+
         return output;
     }
 
     pub fn from_buffer(input: ManagedBuffer<M>) -> Self {
         let parts = input.split(b':');
 
-        if parts.len() != 2 {
+        if parts.len() != 2 && parts.len() != 3 {
             M::error_api_impl().signal_error(b"cannot decode EquippableNftAttribute");
         }
 
+        let unlock_timestamp = if parts.len() == 3 {
+            let lock = parts.get(2).deref().clone();
+
+            if lock == ManagedBuffer::<M>::new_from_bytes(PERMANENT_LOCK_NAME) {
+                PERMANENT_LOCK
+            } else {
+                match lock.parse_u64() {
+                    Some(timestamp) => timestamp,
+                    None => M::error_api_impl().signal_error(b"cannot decode the lock of a slot"),
+                }
+            }
+        } else {
+            0
+        };
+
         let name = parts.get(1).deref().clone();
 
         let opt_name = if name == ManagedBuffer::<M>::new_from_bytes(UNEQUIPPED_ITEM_NAME) {
@@ -56,6 +82,7 @@ impl<M: ManagedTypeApi> EquippableAttribute<M> {
         return Self {
             slot: parts.get(0).deref().clone(),
             name: opt_name,
+            unlock_timestamp,
         };
     }
 }
@@ -124,6 +151,10 @@ impl<M: ManagedTypeApi> TopDecode for EquippableAttributes<M> {
             let attribute = EquippableAttribute::from_buffer(item_raw.deref().clone());
 
             equippable_attributes.set_item_if_empty(&attribute.slot, attribute.name);
+
+            if attribute.unlock_timestamp > 0 {
+                equippable_attributes.lock_slot(&attribute.slot, attribute.unlock_timestamp);
+            }
         }
 
         return Result::Ok(equippable_attributes);
@@ -169,6 +200,70 @@ impl<M: ManagedTypeApi> EquippableAttributes<M> {
         }
     }
 
+    /// Returns every item equipped, ignoring the empty slots.
+    pub fn get_equipped_items(&self) -> ManagedVec<M, Item<M>> {
+        let mut output = ManagedVec::new();
+
+        for kvp in self.items.iter() {
+            if let Some(name) = kvp.name {
+                output.push(Item {
+                    name,
+                    slot: kvp.slot,
+                });
+            }
+        }
+
+        return output;
+    }
+
+    /// Returns the timestamp from which the slot can be unequipped, zero if it is not locked.
+    pub fn get_unlock_timestamp(&self, slot: &ManagedBuffer<M>) -> u64 {
+        if let Some(index) = self.get_index(&slot) {
+            return self.items.get(index).unlock_timestamp;
+        } else {
+            return 0;
+        }
+    }
+
+    /// Lock the item equipped in the slot until the timestamp. Use PERMANENT_LOCK to lock it forever.
+    pub fn lock_slot(&mut self, slot: &ManagedBuffer<M>, unlock_timestamp: u64) {
+        let index = match self.get_index(&slot) {
+            Some(index) => index,
+            None => M::error_api_impl().signal_error(b"Cannot lock an empty slot."),
+        };
+
+        let mut attribute = self.items.get(index);
+        if attribute.name.is_none() {
+            M::error_api_impl().signal_error(b"Cannot lock an empty slot.");
+        }
+
+        attribute.unlock_timestamp = unlock_timestamp;
+
+        let _ = self.items.set(index, &attribute);
+    }
+
+    /// Returns the same attributes, without the locks. Locks do not change the image of the equippable.
+    pub fn without_locks(&self) -> Self {
+        let mut output = Self::empty();
+
+        for kvp in self.items.iter() {
+            output.set_item(&kvp.slot, kvp.name);
+        }
+
+        return output;
+    }
+
+    /// Returns every slot, including the empty ones.
+    pub fn get_slots(&self) -> ManagedVec<M, ManagedBuffer<M>> {
+        let mut output = ManagedVec::new();
+
+        for kvp in self.items.iter() {
+            output.push(kvp.slot);
+        }
+
+        return output;
+    }
+
     pub fn set_item_if_empty(&mut self, slot: &ManagedBuffer<M>, name: Option<ManagedBuffer<M>>) {
         if self.is_slot_empty(slot) == false {
             M::error_api_impl()
@@ -187,6 +282,7 @@ impl<M: ManagedTypeApi> EquippableAttributes<M> {
         let new_equippable_attribute = EquippableAttribute {
             slot: slot.clone(),
             name: opt_name,
+            unlock_timestamp: 0,
         };
 
         match index {
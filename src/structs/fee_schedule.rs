@@ -0,0 +1,20 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Fee charged on each customization of an equippable of the collection, in a given token.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct FeeSchedule<M: ManagedTypeApi> {
+    pub flat: BigUint<M>,
+    /// Charged for each slot whose item changes.
+    pub per_slot: BigUint<M>,
+    /// Charged for each item equipped.
+    pub per_item: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> FeeSchedule<M> {
+    pub fn get_fee(&self, changed_slots_count: usize, items_count: usize) -> BigUint<M> {
+        return &self.flat
+            + &(&self.per_slot * changed_slots_count as u64)
+            + &(&self.per_item * items_count as u64);
+    }
+}
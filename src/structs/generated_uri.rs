@@ -0,0 +1,20 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// A URI added to every customized equippable, built as `prefix + hash of the attributes + suffix`.
+/// e.g. a metadata JSON served at `https://example.com/metadata/<hash>.json`.
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Debug,
+)]
+pub struct GeneratedUri<M: ManagedTypeApi> {
+    pub prefix: ManagedBuffer<M>,
+    pub suffix: ManagedBuffer<M>,
+}
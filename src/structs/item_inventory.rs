@@ -0,0 +1,40 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The ledger of an item token held by the contract.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct ItemInventory<M: ManagedTypeApi> {
+    /// Quantity sent through the fill endpoint.
+    pub deposited: BigUint<M>,
+    /// Quantity sent to users when unequipping.
+    pub paid_out: BigUint<M>,
+    /// Quantity withdrawn by the owner.
+    pub withdrawn: BigUint<M>,
+    /// Quantity currently carried by equippables.
+    pub equipped: BigUint<M>,
+    /// Quantity currently held by the contract, equipped ones included.
+    pub stock: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> ItemInventory<M> {
+    /// The stock that is not carried by any equippable.
+    pub fn get_available(&self) -> BigUint<M> {
+        if self.stock > self.equipped {
+            return &self.stock - &self.equipped;
+        } else {
+            return BigUint::zero();
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> Default for ItemInventory<M> {
+    fn default() -> Self {
+        Self {
+            deposited: BigUint::zero(),
+            paid_out: BigUint::zero(),
+            withdrawn: BigUint::zero(),
+            equipped: BigUint::zero(),
+            stock: BigUint::zero(),
+        }
+    }
+}
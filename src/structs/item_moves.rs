@@ -0,0 +1,34 @@
+use super::token::Token;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The item tokens moved by a customization, between the user and the stock of the smart contract.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Debug)]
+pub struct ItemMoves<M: ManagedTypeApi> {
+    /// Unequipped tokens, taken out of the stock.
+    pub paid_out: ManagedVec<M, Token<M>>,
+    /// Tokens whose equipped count has been released by the payouts.
+    pub released: ManagedVec<M, Token<M>>,
+    /// Unequipped tokens, to mint.
+    pub to_mint: ManagedVec<M, Token<M>>,
+    /// Received tokens, to keep in the stock as equipped.
+    pub equipped: ManagedVec<M, Token<M>>,
+    /// Received tokens, unequipped again by the same customization.
+    pub returned: ManagedVec<M, Token<M>>,
+    /// Received tokens, to burn.
+    pub to_burn: ManagedVec<M, Token<M>>,
+}
+
+impl<M: ManagedTypeApi> Default for ItemMoves<M> {
+    fn default() -> Self {
+        Self {
+            paid_out: ManagedVec::new(),
+            released: ManagedVec::new(),
+            to_mint: ManagedVec::new(),
+            equipped: ManagedVec::new(),
+            returned: ManagedVec::new(),
+            to_burn: ManagedVec::new(),
+        }
+    }
+}
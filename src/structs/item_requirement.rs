@@ -0,0 +1,20 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// An item that must be equipped for another item to be equipped.
+/// If `name` is None, any item in the slot fulfills the requirement.
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Debug,
+)]
+pub struct ItemRequirement<M: ManagedTypeApi> {
+    pub slot: ManagedBuffer<M>,
+    pub name: Option<ManagedBuffer<M>>,
+}
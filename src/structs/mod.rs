@@ -1,3 +1,13 @@
+pub mod customize_simulation;
 pub mod equippable_attributes;
+pub mod fee_schedule;
+pub mod generated_uri;
 pub mod item;
+pub mod item_inventory;
+pub mod item_moves;
+pub mod item_requirement;
+pub mod pending_customization;
+pub mod registry_change;
+pub mod supply_mode;
 pub mod token;
+pub mod update_mode;
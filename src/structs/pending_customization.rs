@@ -0,0 +1,22 @@
+use super::{equippable_attributes::EquippableAttributes, item_moves::ItemMoves};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// A customization held in escrow until the image of its attributes is rendered.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Debug)]
+pub struct PendingCustomization<M: ManagedTypeApi> {
+    /// The address that sent the equippable, which receives it once customized.
+    pub owner: ManagedAddress<M>,
+    pub items: ManagedVec<M, EsdtTokenPayment<M>>,
+    /// The items moved when the customization was held; the unequipped ones are already taken out of the stock.
+    pub moves: ItemMoves<M>,
+    /// The attributes given to the equippable once finalized.
+    pub new_attributes: EquippableAttributes<M>,
+    /// The attributes to render, without the locks.
+    pub attributes: EquippableAttributes<M>,
+    pub name: ManagedBuffer<M>,
+    /// The fee taken from the credit of the owner, paid to the beneficiaries once finalized.
+    pub fee_token: EgldOrEsdtTokenIdentifier<M>,
+    pub fee: BigUint<M>,
+}
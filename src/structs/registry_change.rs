@@ -0,0 +1,22 @@
+use super::{item::Item, token::Token};
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub enum RegistryChangeKind {
+    Unregister,
+    Remap,
+}
+
+/// An entry of the audit log of the items registry.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct RegistryChange<M: ManagedTypeApi> {
+    pub equippable_token_id: TokenIdentifier<M>,
+    pub kind: RegistryChangeKind,
+    pub old_item: Item<M>,
+    pub old_token: Token<M>,
+    pub new_item: Option<Item<M>>,
+    pub new_token: Option<Token<M>>,
+    pub timestamp: u64,
+}
@@ -0,0 +1,12 @@
+elrond_wasm::derive_imports!();
+
+/// How the contract gets the tokens of an item to send back to users.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
+pub enum SupplyMode {
+    /// The tokens received on equip are kept, and sent back on unequip. The stock is filled by the owner.
+    Custody,
+    /// The tokens received on equip are burned, and minted back on unequip.
+    Mint,
+}
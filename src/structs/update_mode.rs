@@ -0,0 +1,12 @@
+elrond_wasm::derive_imports!();
+
+/// How a customized equippable gets its new attributes.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
+pub enum UpdateMode {
+    /// The equippable is burned and minted again with a new nonce.
+    Remint,
+    /// The attributes of the equippable are updated, and the new image is added to its URIs. The nonce is kept.
+    InPlace,
+}
@@ -15,6 +15,17 @@ pub trait ManagedBufferUtils<M: ManagedTypeApi> {
 
     /// Returns 0 if equals. Return 1 if self is after other in the alphabetically order. Returns 0 if self is before other in the alphabetically order.
     fn compare(&self, other: &Self) -> Ordering;
+
+    /// Parse a decimal number. Returns None if the buffer contains anything else than digits, or overflows.
+    fn parse_u64(&self) -> Option<u64>;
+
+    /// Append the decimal representation of the number.
+    fn append_u64(&mut self, value: u64);
+
+    /// Append the lowercase hexadecimal representation of the bytes of `other`.
+    fn append_hex(&mut self, other: &ManagedBuffer<M>);
+
+    fn starts_with(&self, prefix: &ManagedBuffer<M>) -> bool;
 }
 
 impl<M: ManagedTypeApi> ManagedBufferUtils<M> for ManagedBuffer<M> {
@@ -74,4 +85,61 @@ impl<M: ManagedTypeApi> ManagedBufferUtils<M> for ManagedBuffer<M> {
 
         return a_bytes.cmp(&b_bytes);
     }
+
+    fn parse_u64(&self) -> Option<u64> {
+        if self.len() == 0 || self.len() > 20 {
+            return None;
+        }
+
+        let bytes = self.load_512_bytes();
+        let mut output: u64 = 0;
+
+        for &byte in bytes[..self.len()].iter() {
+            if byte < b'0' || byte > b'9' {
+                return None;
+            }
+
+            output = output.checked_mul(10)?.checked_add((byte - b'0') as u64)?;
+        }
+
+        return Some(output);
+    }
+
+    fn append_u64(&mut self, value: u64) {
+        let mut digits: [u8; 20] = [0; 20];
+        let mut remaining = value;
+        let mut start = digits.len();
+
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (remaining % 10) as u8;
+            remaining /= 10;
+
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        self.append_bytes(&digits[start..]);
+    }
+
+    fn append_hex(&mut self, other: &ManagedBuffer<M>) {
+        const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+        let bytes = other.load_512_bytes();
+
+        for &byte in bytes[..other.len()].iter() {
+            self.append_bytes(&[
+                HEX_CHARS[(byte >> 4) as usize],
+                HEX_CHARS[(byte & 0x0f) as usize],
+            ]);
+        }
+    }
+
+    fn starts_with(&self, prefix: &ManagedBuffer<M>) -> bool {
+        return match self.copy_slice(0, prefix.len()) {
+            Some(start) => &start == prefix,
+            None => false,
+        };
+    }
 }
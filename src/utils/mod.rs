@@ -1,3 +1,2 @@
-pub mod bidimapper_utils;
 pub mod managed_buffer_utils;
 pub mod managed_vec_utils;
@@ -1,3 +1,4 @@
+use customize_nft::libs::fees::FeesModule;
 use customize_nft::{EndpointWrappers, Equip};
 use elrond_wasm_debug::rust_biguint;
 
@@ -33,6 +34,57 @@ fn works() {
     );
 }
 
+#[test]
+fn keep_fee_credits() {
+    const EGLD_AMOUNT: u64 = 1_000_000;
+    const FEE_CREDIT: u64 = 400;
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.cf_wrapper.address_ref(), &rust_biguint!(EGLD_AMOUNT));
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.first_user_address, &rust_biguint!(FEE_CREDIT));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(FEE_CREDIT),
+            |sc| {
+                sc.deposit_fee_credit();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim();
+            },
+        )
+        .assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(&setup.owner_address),
+        rust_biguint!(EGLD_AMOUNT)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(&setup.cf_wrapper.address_ref()),
+        rust_biguint!(FEE_CREDIT)
+    );
+}
+
 #[test]
 fn panic_if_not_owner() {
     let mut setup = testing_utils::setup(customize_nft::contract_obj);
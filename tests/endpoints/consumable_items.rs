@@ -0,0 +1,105 @@
+use customize_nft::constants::UNEQUIPPED_ITEM_NAME;
+use customize_nft::Equip;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"tattoo";
+const ITEM_NAME: &[u8] = b"Anchor";
+const ITEM_ID: &[u8] = b"TATTOO-a1a1a1";
+
+#[test]
+fn burn_on_equip_and_send_nothing_on_unequip() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, 1, &TestItemAttributes {});
+    set_item_consumable(&mut setup);
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(ITEM_ID, 1, 1);
+    setup.set_uri_of_items(&[(SLOT, ITEM_NAME)], b"https://ipfs.io/ipfs/anchor");
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (ITEM_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(setup.cf_wrapper.address_ref(), ITEM_ID, 1),
+        rust_biguint!(2)
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_new_nonce.unwrap())]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, 1),
+        rust_biguint!(0)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(setup.cf_wrapper.address_ref(), ITEM_ID, 1),
+        rust_biguint!(2)
+    );
+}
+
+#[test]
+fn get_items_shows_consumable_flag() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, 1, &TestItemAttributes {});
+    set_item_consumable(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let (_, _, _, _, is_consumable) = sc
+                .get_items(managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                .into_iter()
+                .next()
+                .unwrap()
+                .into_tuple();
+
+            assert_eq!(is_consumable, true);
+        })
+        .assert_ok();
+}
+
+fn set_item_consumable<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_item_consumable(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                    true,
+                );
+            },
+        )
+        .assert_ok();
+}
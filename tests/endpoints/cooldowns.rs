@@ -0,0 +1,167 @@
+use customize_nft::constants::{
+    ERR_ADDRESS_COOLDOWN_NOT_ELAPSED, ERR_EQUIPPABLE_COOLDOWN_NOT_ELAPSED, UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::cooldowns::CooldownsModule;
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::{managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+const START_TIMESTAMP: u64 = 1_000;
+const COOLDOWN: u64 = 100;
+
+#[test]
+fn equippable_cooldown_follows_reminted_nonce() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    prepare(&mut setup, COOLDOWN, 0);
+    setup.create_empty_equippable(30);
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, 30),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+    let new_nonce = opt_new_nonce.unwrap();
+
+    assert_next_customization_time(&mut setup, new_nonce, START_TIMESTAMP + COOLDOWN);
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, new_nonce)]),
+        &[b"hat"],
+    );
+    tx_result.assert_user_error(ERR_EQUIPPABLE_COOLDOWN_NOT_ELAPSED);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP + COOLDOWN);
+    assert_next_customization_time(&mut setup, new_nonce, 0);
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, new_nonce)]),
+        &[b"hat"],
+    );
+    tx_result.assert_ok();
+}
+
+#[test]
+fn address_cooldown_applies_to_other_equippables() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    prepare(&mut setup, 0, COOLDOWN);
+    setup.create_empty_equippable(30);
+    setup.create_empty_equippable(31);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, 30),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP + 1);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, 31),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_user_error(ERR_ADDRESS_COOLDOWN_NOT_ELAPSED);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP + COOLDOWN);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, 31),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+}
+
+#[test]
+fn address_cooldown_applies_to_another_transaction_at_the_same_timestamp() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    prepare(&mut setup, 0, COOLDOWN);
+    setup.create_empty_equippable(30);
+    setup.create_empty_equippable(31);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, 30),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, 31),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_user_error(ERR_ADDRESS_COOLDOWN_NOT_ELAPSED);
+}
+
+fn prepare<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    equippable_cooldown: u64,
+    address_cooldown: u64,
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    DebugApi::dummy();
+
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 2);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_customization_cooldowns(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    equippable_cooldown,
+                    address_cooldown,
+                );
+            },
+        )
+        .assert_ok();
+}
+
+fn assert_next_customization_time<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    equippable_nonce: u64,
+    expected_time: u64,
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let user = setup.first_user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.get_next_customization_time(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    equippable_nonce,
+                    ManagedAddress::from_address(&user),
+                ),
+                expected_time
+            );
+        })
+        .assert_ok();
+}
@@ -0,0 +1,212 @@
+use customize_nft::constants::{
+    ERR_ADDRESS_COOLDOWN_NOT_ELAPSED, ERR_BATCH_PAYMENTS_DO_NOT_MATCH_INSTRUCTIONS,
+    UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::cooldowns::CooldownsModule;
+use customize_nft::libs::customize::CustomizeModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::elrond_codec::multi_types::MultiValue2;
+use elrond_wasm::types::{ManagedBuffer, ManagedVec, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::{TxInputESDT, TxResult};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const FIRST_NONCE: u64 = 30;
+const SECOND_NONCE: u64 = 31;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn equip_and_unequip_two_equippables() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        FIRST_NONCE,
+        HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.create_empty_equippable(SECOND_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+
+    let (opt_new_nonces, tx_result) = customize_batch(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, FIRST_NONCE),
+            (EQUIPPABLE_TOKEN_ID, SECOND_NONCE),
+            (HAT_ID, 1),
+        ]),
+        &[(0, &[b"hat"]), (1, &[])],
+    );
+    tx_result.assert_ok();
+
+    let new_nonces = opt_new_nonces.unwrap();
+    assert_eq!(new_nonces.len(), 2);
+
+    let mut emptied_attributes = EquippableAttributes::<DebugApi>::empty();
+    emptied_attributes.empty_slot(&managed_buffer!(b"hat"));
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        new_nonces[0],
+        &rust_biguint!(1),
+        Option::Some(&emptied_attributes),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        new_nonces[1],
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(b"hat"),
+            name: managed_buffer!(b"Pirate Hat"),
+        }])),
+    );
+
+    // the hat unequipped from the first equippable is sent back
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, HAT_ID, 1),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn address_cooldown_applies_between_transactions_only() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(FIRST_NONCE);
+    setup.create_empty_equippable(SECOND_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 2);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+    setup.blockchain_wrapper.set_block_timestamp(1_000);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_customization_cooldowns(managed_token_id!(EQUIPPABLE_TOKEN_ID), 0, 100);
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = customize_batch(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, FIRST_NONCE),
+            (HAT_ID, 1),
+            (EQUIPPABLE_TOKEN_ID, SECOND_NONCE),
+            (HAT_ID, 1),
+        ]),
+        &[(1, &[]), (1, &[])],
+    );
+    tx_result.assert_ok();
+
+    setup.create_empty_equippable(FIRST_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+
+    let (_, tx_result) = customize_batch(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, FIRST_NONCE), (HAT_ID, 1)]),
+        &[(1, &[])],
+    );
+    tx_result.assert_user_error(ERR_ADDRESS_COOLDOWN_NOT_ELAPSED);
+}
+
+#[test]
+fn panic_if_items_count_exceeds_payments() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_empty_equippable(FIRST_NONCE);
+
+    let (_, tx_result) = customize_batch(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, FIRST_NONCE)]),
+        &[(1, &[])],
+    );
+    tx_result.assert_user_error(ERR_BATCH_PAYMENTS_DO_NOT_MATCH_INSTRUCTIONS);
+}
+
+#[test]
+fn panic_if_payments_left_without_instruction() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_equippable_with_registered_item(
+        FIRST_NONCE,
+        HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.create_empty_equippable(SECOND_NONCE);
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (_, tx_result) = customize_batch(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, FIRST_NONCE),
+            (EQUIPPABLE_TOKEN_ID, SECOND_NONCE),
+        ]),
+        &[(0, &[b"hat"])],
+    );
+    tx_result.assert_user_error(ERR_BATCH_PAYMENTS_DO_NOT_MATCH_INSTRUCTIONS);
+}
+
+/// Each instruction is the number of items following the equippable, and the slots to unequip.
+fn customize_batch<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    transfers: Vec<TxInputESDT>,
+    instructions: &[(u32, &[&[u8]])],
+) -> (Option<Vec<u64>>, TxResult)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let mut opt_sc_result = Option::None;
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_multi_transfer(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        &transfers,
+        |sc| {
+            let mut instructions_managed = MultiValueEncoded::new();
+
+            for (items_count, slots) in instructions {
+                let mut slots_managed = ManagedVec::<DebugApi, ManagedBuffer<DebugApi>>::new();
+
+                for slot in slots.iter() {
+                    slots_managed.push(managed_buffer!(slot));
+                }
+
+                instructions_managed.push(MultiValue2::from((*items_count, slots_managed)));
+            }
+
+            let new_nonces = sc.customize_batch(instructions_managed);
+
+            opt_sc_result = Option::Some(new_nonces.to_vec().iter().collect::<Vec<u64>>());
+        },
+    );
+
+    return (opt_sc_result, tx_result);
+}
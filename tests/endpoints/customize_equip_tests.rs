@@ -3,13 +3,13 @@ use customize_nft::constants::{
     ERR_FIRST_PAYMENT_IS_EQUIPPABLE, ERR_MORE_THAN_ONE_ITEM_RECEIVED,
     ERR_NEED_ONE_ITEM_OR_UNEQUIP_SLOT,
 };
-use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::uris::UrisModule;
 use customize_nft::structs::equippable_attributes::EquippableAttributes;
 use customize_nft::structs::item::Item;
 use elrond_wasm::types::EsdtTokenType;
 use elrond_wasm_debug::managed_buffer;
 use elrond_wasm_debug::tx_mock::TxInputESDT;
-use elrond_wasm_debug::{rust_biguint, DebugApi};
+use elrond_wasm_debug::{managed_token_id, rust_biguint, DebugApi};
 
 use crate::testing_utils;
 
@@ -71,8 +71,12 @@ fn test_equip() {
                 let attributes_before_custom = EquippableAttributes::<DebugApi>::empty();
                 let name_before_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_before_custom, &name_before_custom)
-                    .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_before_custom,
+                    &name_before_custom,
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
 
                 let attributes_after_custom = EquippableAttributes::<DebugApi>::new(&[Item {
                     name: managed_buffer!(ITEM_TO_EQUIP_NAME),
@@ -80,8 +84,12 @@ fn test_equip() {
                 }]);
                 let name_after_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_after_custom, &name_after_custom)
-                    .set(managed_buffer!(b"https://ipfs.io/ipfs/after custom"));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_after_custom,
+                    &name_after_custom,
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/after custom"));
             },
         )
         .assert_ok();
@@ -192,8 +200,12 @@ fn should_replace_item() {
                 }]);
                 let name_before_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_before_custom, &name_before_custom)
-                    .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_before_custom,
+                    &name_before_custom,
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
 
                 let attributes_after_custom = EquippableAttributes::<DebugApi>::new(&[Item {
                     name: managed_buffer!(ITEM_TO_EQUIP_NAME),
@@ -201,8 +213,12 @@ fn should_replace_item() {
                 }]);
                 let name_after_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_after_custom, &name_after_custom)
-                    .set(managed_buffer!(b"https://ipfs.io/ipfs/cid after custom"));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_after_custom,
+                    &name_after_custom,
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/cid after custom"));
             },
         )
         .assert_ok();
@@ -538,11 +554,19 @@ fn equip_while_sending_twice_same_items() {
                 }]);
                 let name_after_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_before_custom, &name_before_custom)
-                    .set(&managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
-
-                sc.uris_of_attributes(&attributes_after_custom, &name_after_custom)
-                    .set(&managed_buffer!(b"https://ipfs.io/ipfs/cid after custom"));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_before_custom,
+                    &name_before_custom,
+                )
+                .set(&managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
+
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_after_custom,
+                    &name_after_custom,
+                )
+                .set(&managed_buffer!(b"https://ipfs.io/ipfs/cid after custom"));
             },
         )
         .assert_ok();
@@ -676,11 +700,19 @@ fn equip_while_sending_two_items_of_same_slot() {
                 }]);
                 let name_after_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_before_custom, &name_before_custom)
-                    .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
-
-                sc.uris_of_attributes(&attributes_after_custom, &name_after_custom)
-                    .set(managed_buffer!(b"https://ipfs.io/ipfs/cid after custom"));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_before_custom,
+                    &name_before_custom,
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
+
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_after_custom,
+                    &name_after_custom,
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/cid after custom"));
             },
         )
         .assert_ok();
@@ -1,10 +1,10 @@
 use customize_nft::{
     constants::ERR_NEED_ONE_ITEM_OR_UNEQUIP_SLOT,
-    libs::{customize::CustomizeModule, equippable_uris::EquippableUrisModule},
+    libs::{customize::CustomizeModule, uris::UrisModule},
     structs::{equippable_attributes::EquippableAttributes, item::Item},
 };
 use elrond_wasm::types::{ManagedBuffer, MultiValueEncoded};
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
 use crate::testing_utils::{self, New, TestItemAttributes};
 
@@ -78,11 +78,13 @@ fn customize_complete_flow() {
                 );
 
                 sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
                     &attributes_before_custom,
                     &managed_buffer!(EQUIPPABLE_TOKEN_ID),
                 )
                 .set(managed_buffer!(b"https://ipfs.io/ipfs/cid before custom"));
                 sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
                     &attributes_after_custom,
                     &managed_buffer!(EQUIPPABLE_TOKEN_ID),
                 )
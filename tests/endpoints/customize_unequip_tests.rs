@@ -1,10 +1,10 @@
 use customize_nft::{
     constants::ERR_CANNOT_UNEQUIP_EMPTY_SLOT,
-    libs::equippable_uris::EquippableUrisModule,
+    libs::uris::UrisModule,
     structs::{equippable_attributes::EquippableAttributes, item::Item},
 };
 use elrond_wasm::types::ManagedBuffer;
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
 use crate::testing_utils::{self, New, TestItemAttributes};
 
@@ -48,6 +48,7 @@ fn customize_only_unequip() {
                 attributes_after_custom.empty_slot(&managed_buffer!(slot));
 
                 sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
                     &attributes_before_custom,
                     &managed_buffer!(EQUIPPABLE_TOKEN_ID),
                 )
@@ -56,6 +57,7 @@ fn customize_only_unequip() {
                 ));
 
                 sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
                     &attributes_after_custom,
                     &managed_buffer!(EQUIPPABLE_TOKEN_ID),
                 )
@@ -150,18 +152,26 @@ fn panic_when_unequip_twice_the_same_slot() {
                 }]);
                 let name_before_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_before_custom, &name_before_custom)
-                    .set(ManagedBuffer::<DebugApi>::new_from_bytes(
-                        b"https://ipfs.io/ipfs/before",
-                    ));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_before_custom,
+                    &name_before_custom,
+                )
+                .set(ManagedBuffer::<DebugApi>::new_from_bytes(
+                    b"https://ipfs.io/ipfs/before",
+                ));
 
                 let attributes_after_custom = EquippableAttributes::<DebugApi>::empty();
                 let name_after_custom = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes_after_custom, &name_after_custom)
-                    .set(ManagedBuffer::<DebugApi>::new_from_bytes(
-                        b"https://ipfs.io/ipfs/after",
-                    ));
+                sc.uris_of_attributes(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_after_custom,
+                    &name_after_custom,
+                )
+                .set(ManagedBuffer::<DebugApi>::new_from_bytes(
+                    b"https://ipfs.io/ipfs/after",
+                ));
             },
         )
         .assert_ok();
@@ -196,7 +206,7 @@ fn panic_when_unequip_on_empty_slot() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(EQUIPPABLE_TOKEN_ID);
 
-                sc.uris_of_attributes(&attributes, &name)
+                sc.uris_of_attributes(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &attributes, &name)
                     .set(managed_buffer!(b"https://ipfs.io/ipfs/empty"));
             },
         )
@@ -0,0 +1,297 @@
+use customize_nft::constants::{
+    ERR_CANNOT_REGISTER_ITEM_AS_EQUIPPABLE, ERR_COLLECTION_HAS_PENDING_RENDERS,
+    ERR_COLLECTION_HAS_REGISTERED_ITEMS, ERR_EQUIPPABLE_COLLECTION_ALREADY_ADDED,
+};
+use customize_nft::libs::slots::SlotsModule;
+use customize_nft::libs::storage::StorageModule;
+use customize_nft::libs::uris::UrisModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use customize_nft::Equip;
+use elrond_wasm::elrond_codec::multi_types::MultiValue4;
+use elrond_wasm::types::MultiValueEncoded;
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const SHIP_TOKEN_ID: &[u8] = b"SHIP-b2b2b2";
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const ENGINE_ID: &[u8] = b"ENGINE-c3c3c3";
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn customize_equippable_of_another_collection() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    add_equippable_collection(&mut setup, SHIP_TOKEN_ID).assert_ok();
+    setup.set_all_permissions_on_token(SHIP_TOKEN_ID);
+    register_ship_item(&mut setup, b"engine", b"Turbo", ENGINE_ID);
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        SHIP_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::empty(),
+    );
+    setup.add_random_item_to_user(ENGINE_ID, 1, 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let attributes = EquippableAttributes::<DebugApi>::new(&[Item {
+                    slot: managed_buffer!(b"engine"),
+                    name: managed_buffer!(b"Turbo"),
+                }]);
+
+                sc.uris_of_attributes(
+                    &managed_token_id!(SHIP_TOKEN_ID),
+                    &attributes,
+                    &managed_buffer!(SHIP_TOKEN_ID),
+                )
+                .set(managed_buffer!(b"https://ipfs.io/ipfs/turbo"));
+            },
+        )
+        .assert_ok();
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (SHIP_TOKEN_ID, EQUIPPABLE_NONCE),
+        (ENGINE_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        SHIP_TOKEN_ID,
+        opt_new_nonce.unwrap(),
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(b"engine"),
+            name: managed_buffer!(b"Turbo"),
+        }])),
+    );
+}
+
+#[test]
+fn panic_if_item_registered_in_another_collection() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    add_equippable_collection(&mut setup, SHIP_TOKEN_ID).assert_ok();
+    setup.set_all_permissions_on_token(SHIP_TOKEN_ID);
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        SHIP_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::empty(),
+    );
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (SHIP_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+
+    tx_result.assert_user_error("The item you are equipping HAT-a1a1a1 1 is not registered.");
+}
+
+#[test]
+fn slots_are_registered_per_collection() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    add_equippable_collection(&mut setup, SHIP_TOKEN_ID).assert_ok();
+    setup.register_slots(&[(b"hat", 0)]);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.get_slots(managed_token_id!(EQUIPPABLE_TOKEN_ID)).len(),
+                1
+            );
+            assert_eq!(sc.get_slots(managed_token_id!(SHIP_TOKEN_ID)).len(), 0);
+            assert_eq!(sc.get_equippable_collections().len(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_collection_already_added() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    add_equippable_collection(&mut setup, EQUIPPABLE_TOKEN_ID)
+        .assert_user_error(ERR_EQUIPPABLE_COLLECTION_ALREADY_ADDED);
+}
+
+#[test]
+fn panic_if_collection_is_an_item() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+
+    add_equippable_collection(&mut setup, HAT_ID)
+        .assert_user_error(ERR_CANNOT_REGISTER_ITEM_AS_EQUIPPABLE);
+}
+
+#[test]
+fn panic_if_remove_collection_with_items() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_equippable_collection(managed_token_id!(EQUIPPABLE_TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_COLLECTION_HAS_REGISTERED_ITEMS);
+}
+
+#[test]
+fn forget_settings_of_removed_collection() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_slots(&[(b"hat", 0)]);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_max_slots_per_equippable(managed_token_id!(EQUIPPABLE_TOKEN_ID), 1);
+                sc.remove_equippable_collection(managed_token_id!(EQUIPPABLE_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    add_equippable_collection(&mut setup, EQUIPPABLE_TOKEN_ID).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.get_slots(managed_token_id!(EQUIPPABLE_TOKEN_ID)).len(),
+                0
+            );
+            assert_eq!(
+                sc.max_slots_per_equippable(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .get(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_remove_collection_with_pending_render() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .insert(
+                        managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                        EquippableAttributes::<DebugApi>::empty(),
+                    );
+
+                sc.remove_equippable_collection(managed_token_id!(EQUIPPABLE_TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_COLLECTION_HAS_PENDING_RENDERS);
+}
+
+fn add_equippable_collection<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    equippable_token_id: &[u8],
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.add_equippable_collection(managed_token_id!(equippable_token_id));
+        },
+    );
+}
+
+fn register_ship_item<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    slot: &[u8],
+    name: &[u8],
+    item_id: &[u8],
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue4::from((
+                    managed_buffer!(slot),
+                    managed_buffer!(name),
+                    managed_token_id!(item_id),
+                    1u64,
+                )));
+
+                sc.register_item(managed_token_id!(SHIP_TOKEN_ID), items);
+
+                assert_eq!(
+                    sc.registered_items(&managed_token_id!(SHIP_TOKEN_ID)).len(),
+                    1
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.owner_address,
+        item_id,
+        1,
+        &rust_biguint!(2),
+        &TestItemAttributes {},
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            item_id,
+            1,
+            &rust_biguint!(2),
+            |sc| {
+                sc.fill();
+            },
+        )
+        .assert_ok();
+}
@@ -0,0 +1,468 @@
+use customize_nft::constants::{
+    ERR_NOT_PENDING_CUSTOMIZATION_OWNER, ERR_PENDING_CUSTOMIZATION_NOT_FOUND,
+    ERR_RENDER_ALREADY_IN_QUEUE, UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::customize::CustomizeModule;
+use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::escrow::EscrowModule;
+use customize_nft::libs::inventory::InventoryModule;
+use customize_nft::libs::uris::UrisModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::{Address, BigUint, ManagedAddress, ManagedBuffer, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::args_set_cid_of;
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_URI: &[u8] = b"https://ipfs.io/ipfs/hat";
+
+#[test]
+fn hold_then_finalize_on_render() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    hold_hat_customization(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .get(&managed_buffer!(EQUIPPABLE_TOKEN_ID)),
+                Option::Some(get_hat_attributes())
+            );
+            assert_eq!(
+                sc.get_pending_customizations(ManagedAddress::from_address(
+                    &setup.first_user_address
+                ))
+                .len(),
+                1
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    get_hat_attributes(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(HAT_URI)
+                ));
+            },
+        )
+        .assert_ok();
+
+    setup.assert_is_burn(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE);
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&get_hat_attributes()),
+    );
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_customizations(ManagedAddress::from_address(
+                    &setup.first_user_address
+                ))
+                .len(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn cancel_pending_customization() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    hold_hat_customization(&mut setup);
+
+    let first_user_address = setup.first_user_address.clone();
+    cancel_customization(&mut setup, &first_user_address).assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::empty()),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        HAT_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&TestItemAttributes {}),
+    );
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .is_empty(),
+                true
+            );
+        })
+        .assert_ok();
+
+    cancel_customization(&mut setup, &first_user_address)
+        .assert_user_error(ERR_PENDING_CUSTOMIZATION_NOT_FOUND);
+}
+
+#[test]
+fn panic_if_cancel_customization_of_another_address() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    hold_hat_customization(&mut setup);
+
+    let second_user_address = setup.second_user_address.clone();
+    cancel_customization(&mut setup, &second_user_address)
+        .assert_user_error(ERR_NOT_PENDING_CUSTOMIZATION_OWNER);
+}
+
+#[test]
+fn customize_right_away_if_rendered() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.enqueue_and_set_cid_of(
+        &|| (get_hat_attributes(), managed_buffer!(EQUIPPABLE_TOKEN_ID)),
+        HAT_URI,
+    );
+    enable_escrow(&mut setup);
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    let new_nonce = opt_new_nonce.unwrap();
+    assert_ne!(new_nonce, 0);
+    setup.assert_uris(EQUIPPABLE_TOKEN_ID, new_nonce, &[HAT_URI]);
+}
+
+#[test]
+fn keep_unequipped_stock_reserved_until_finalized() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    enable_escrow(&mut setup);
+
+    let (opt_new_nonce, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[b"hat"],
+    );
+    tx_result.assert_ok();
+    assert_eq!(opt_new_nonce, Option::Some(0));
+
+    // the owner withdraws all the available stock before the image is rendered
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let available = sc
+                    .get_inventory(managed_token_id!(HAT_ID), 1)
+                    .get_available();
+
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue3::from((
+                    managed_token_id!(HAT_ID),
+                    1u64,
+                    available,
+                )));
+                sc.withdraw_items(items);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    get_unequipped_hat_attributes(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(HAT_URI)
+                ));
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        HAT_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&TestItemAttributes {}),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&get_unequipped_hat_attributes()),
+    );
+}
+
+#[test]
+fn refund_if_finalization_fails() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    hold_hat_customization(&mut setup);
+
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.cf_wrapper.address_ref(),
+        EQUIPPABLE_TOKEN_ID,
+        &[],
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    get_hat_attributes(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(HAT_URI)
+                ));
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::empty()),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        HAT_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&TestItemAttributes {}),
+    );
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let inventory = sc.get_inventory(managed_token_id!(HAT_ID), 1);
+
+            assert_eq!(inventory.equipped, BigUint::zero());
+            assert_eq!(inventory.stock, BigUint::from(2u32));
+            assert_eq!(
+                sc.get_pending_customizations(ManagedAddress::from_address(
+                    &setup.first_user_address
+                ))
+                .len(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn cancel_after_another_user_unequipped_the_same_token() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    hold_hat_customization(&mut setup);
+
+    // another user unequips a hat, paid from the stock while the held hat is in escrow
+    const OTHER_EQUIPPABLE_NONCE: u64 = 31;
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.second_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        OTHER_EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &get_hat_attributes(),
+    );
+    setup.set_uri_of_items(&[(b"hat", UNEQUIPPED_ITEM_NAME)], HAT_URI);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.second_user_address,
+            &setup.cf_wrapper,
+            &testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, OTHER_EQUIPPABLE_NONCE)]),
+            |sc| {
+                let mut to_unequip_slots =
+                    MultiValueEncoded::<DebugApi, ManagedBuffer<DebugApi>>::new();
+                to_unequip_slots.push(managed_buffer!(b"hat"));
+
+                sc.customize(to_unequip_slots);
+            },
+        )
+        .assert_ok();
+
+    let first_user_address = setup.first_user_address.clone();
+    cancel_customization(&mut setup, &first_user_address).assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        HAT_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&TestItemAttributes {}),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.second_user_address,
+        HAT_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&TestItemAttributes {}),
+    );
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let inventory = sc.get_inventory(managed_token_id!(HAT_ID), 1);
+
+            assert_eq!(inventory.equipped, BigUint::zero());
+            assert_eq!(inventory.stock, BigUint::from(1u32));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_another_render_is_queued_for_the_name() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    enable_escrow(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .insert(
+                        managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                        EquippableAttributes::<DebugApi>::empty(),
+                    );
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_user_error(ERR_RENDER_ALREADY_IN_QUEUE);
+}
+
+fn get_hat_attributes() -> EquippableAttributes<DebugApi> {
+    return EquippableAttributes::<DebugApi>::new(&[Item {
+        slot: managed_buffer!(b"hat"),
+        name: managed_buffer!(b"Pirate Hat"),
+    }]);
+}
+
+fn get_unequipped_hat_attributes() -> EquippableAttributes<DebugApi> {
+    let mut attributes = get_hat_attributes();
+    attributes.empty_slot(&managed_buffer!(b"hat"));
+
+    return attributes;
+}
+
+fn enable_escrow<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_escrow_enabled(managed_token_id!(EQUIPPABLE_TOKEN_ID), true);
+            },
+        )
+        .assert_ok();
+}
+
+/// Equip a hat whose image is not rendered, with the escrow enabled.
+fn hold_hat_customization<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    enable_escrow(setup);
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    assert_eq!(opt_new_nonce, Option::Some(0));
+    setup
+        .blockchain_wrapper
+        .check_nft_balance::<EquippableAttributes<DebugApi>>(
+            &setup.cf_wrapper.address_ref(),
+            EQUIPPABLE_TOKEN_ID,
+            EQUIPPABLE_NONCE,
+            &rust_biguint!(1),
+            Option::None,
+        );
+}
+
+fn cancel_customization<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    caller: &Address,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        caller,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.cancel_customization(managed_token_id!(EQUIPPABLE_TOKEN_ID), EQUIPPABLE_NONCE);
+        },
+    );
+}
@@ -0,0 +1,155 @@
+use customize_nft::constants::{
+    ERR_EXCLUSION_GROUP_NEEDS_TWO_SLOTS, ERR_EXCLUSION_GROUP_NOT_FOUND,
+};
+use customize_nft::libs::slots::SlotsModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::types::{ManagedBuffer, ManagedVec, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const TOP_ID: &[u8] = b"TOP-a1a1a1";
+const BOTTOM_ID: &[u8] = b"BOTTOM-a1a1a1";
+const SUIT_ID: &[u8] = b"SUIT-a1a1a1";
+
+#[test]
+fn equipping_suit_unequips_top_and_bottom() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"top", b"Shirt", TOP_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"bottom", b"Jeans", BOTTOM_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"suit", b"Tuxedo", SUIT_ID, 1, &TestItemAttributes {});
+
+    set_exclusion_group(&mut setup, b"suit-top", &[b"suit", b"top"]).assert_ok();
+    set_exclusion_group(&mut setup, b"suit-bottom", &[b"suit", b"bottom"]).assert_ok();
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::new(&[
+            Item {
+                slot: managed_buffer!(b"top"),
+                name: managed_buffer!(b"Shirt"),
+            },
+            Item {
+                slot: managed_buffer!(b"bottom"),
+                name: managed_buffer!(b"Jeans"),
+            },
+        ]),
+    );
+    setup.add_random_item_to_user(SUIT_ID, 1, 1);
+    setup.set_uri_of_items(
+        &[
+            (b"top", customize_nft::constants::UNEQUIPPED_ITEM_NAME),
+            (b"bottom", customize_nft::constants::UNEQUIPPED_ITEM_NAME),
+            (b"suit", b"Tuxedo"),
+        ],
+        b"https://ipfs.io/ipfs/tuxedo",
+    );
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (SUIT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, TOP_ID, 1),
+        rust_biguint!(1)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, BOTTOM_ID, 1),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn get_exclusion_groups() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    set_exclusion_group(&mut setup, b"head", &[b"hat", b"helmet"]).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let groups: Vec<_> = sc
+                .get_exclusion_groups(managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                .into_iter()
+                .map(|group| group.into_tuple())
+                .collect();
+
+            let mut expected_slots = ManagedVec::<DebugApi, ManagedBuffer<DebugApi>>::new();
+            expected_slots.push(managed_buffer!(b"hat"));
+            expected_slots.push(managed_buffer!(b"helmet"));
+
+            assert_eq!(groups, vec![(managed_buffer!(b"head"), expected_slots)]);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_less_than_two_slots() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    set_exclusion_group(&mut setup, b"head", &[b"hat"])
+        .assert_user_error(ERR_EXCLUSION_GROUP_NEEDS_TWO_SLOTS);
+}
+
+#[test]
+fn panic_if_remove_unknown_group() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_exclusion_group(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"head"),
+                );
+            },
+        )
+        .assert_user_error(ERR_EXCLUSION_GROUP_NOT_FOUND);
+}
+
+fn set_exclusion_group<CrowdfundingObjBuilder>(
+    setup: &mut EquipSetup<CrowdfundingObjBuilder>,
+    name: &[u8],
+    slots: &[&[u8]],
+) -> TxResult
+where
+    CrowdfundingObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut managed_slots = MultiValueEncoded::new();
+            for slot in slots {
+                managed_slots.push(managed_buffer!(slot));
+            }
+
+            sc.set_exclusion_group(
+                managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                managed_buffer!(name),
+                managed_slots,
+            );
+        },
+    )
+}
@@ -0,0 +1,330 @@
+use customize_nft::constants::{
+    ERR_FEE_BENEFICIARY_DUPLICATED, ERR_FEE_CREDIT_WITHDRAWAL_IS_ZERO,
+    ERR_FEE_SHARES_MUST_ADD_UP_TO_TOTAL, ERR_NOT_ENOUGH_FEE_CREDIT,
+};
+use customize_nft::libs::fees::{count_changed_slots, FeesModule};
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::elrond_codec::multi_types::MultiValue2;
+use elrond_wasm::types::{
+    Address, BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, MultiValueEncoded,
+};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const GAME_TOKEN_ID: &[u8] = b"GAME-a1a1a1";
+
+#[test]
+fn charge_fee_and_split_revenues() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    prepare_hat(&mut setup);
+    set_egld_fee_schedule(&mut setup, 10, 5, 3);
+
+    let owner = setup.owner_address.clone();
+    let partner = setup.second_user_address.clone();
+    set_fee_beneficiaries(&mut setup, &[(&partner, 7_000), (&owner, 3_000)]).assert_ok();
+
+    deposit_egld_fee_credit(&mut setup, 100);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    // fee = 10 + 5 * 1 slot changed + 3 * 1 item = 18
+    let user = setup.first_user_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.fee_credit(
+                    &ManagedAddress::from_address(&user),
+                    &EgldOrEsdtTokenIdentifier::egld()
+                )
+                .get(),
+                BigUint::from(82u64)
+            );
+            assert_eq!(
+                sc.fee_revenue(
+                    &ManagedAddress::from_address(&partner),
+                    &EgldOrEsdtTokenIdentifier::egld()
+                )
+                .get(),
+                BigUint::from(12u64)
+            );
+            assert_eq!(
+                sc.fee_revenue(
+                    &ManagedAddress::from_address(&owner),
+                    &EgldOrEsdtTokenIdentifier::egld()
+                )
+                .get(),
+                BigUint::from(6u64)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&partner, &setup.cf_wrapper, &rust_biguint!(0), |sc| {
+            sc.claim_fee_revenues();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&partner, &rust_biguint!(12));
+}
+
+#[test]
+fn pay_fee_in_any_accepted_token() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    prepare_hat(&mut setup);
+    set_egld_fee_schedule(&mut setup, 10, 5, 3);
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_fee_schedule(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(GAME_TOKEN_ID)),
+                    BigUint::from(1_000u64),
+                    BigUint::zero(),
+                    BigUint::zero(),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_esdt_balance(
+        &setup.first_user_address,
+        GAME_TOKEN_ID,
+        &rust_biguint!(1_500),
+    );
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            GAME_TOKEN_ID,
+            0,
+            &rust_biguint!(1_500),
+            |sc| {
+                sc.deposit_fee_credit();
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    let user = setup.first_user_address.clone();
+    let owner = setup.owner_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let game_token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(GAME_TOKEN_ID));
+
+            assert_eq!(
+                sc.fee_credit(&ManagedAddress::from_address(&user), &game_token)
+                    .get(),
+                BigUint::from(500u64)
+            );
+            assert_eq!(
+                sc.fee_revenue(&ManagedAddress::from_address(&owner), &game_token)
+                    .get(),
+                BigUint::from(1_000u64)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_not_enough_fee_credit() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    prepare_hat(&mut setup);
+    set_egld_fee_schedule(&mut setup, 10, 5, 3);
+
+    deposit_egld_fee_credit(&mut setup, 17);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+
+    tx_result.assert_user_error(ERR_NOT_ENOUGH_FEE_CREDIT);
+}
+
+#[test]
+fn panic_if_shares_do_not_add_up() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let owner = setup.owner_address.clone();
+    let partner = setup.second_user_address.clone();
+
+    set_fee_beneficiaries(&mut setup, &[(&partner, 7_000), (&owner, 2_000)])
+        .assert_user_error(ERR_FEE_SHARES_MUST_ADD_UP_TO_TOTAL);
+}
+
+#[test]
+fn panic_if_beneficiary_listed_twice() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let owner = setup.owner_address.clone();
+    let partner = setup.second_user_address.clone();
+
+    set_fee_beneficiaries(
+        &mut setup,
+        &[(&partner, 5_000), (&owner, 2_500), (&partner, 2_500)],
+    )
+    .assert_user_error(ERR_FEE_BENEFICIARY_DUPLICATED);
+}
+
+#[test]
+fn panic_if_withdraw_zero_fee_credit() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_fee_credit(EgldOrEsdtTokenIdentifier::egld(), BigUint::zero());
+            },
+        )
+        .assert_user_error(ERR_FEE_CREDIT_WITHDRAWAL_IS_ZERO);
+}
+
+#[test]
+fn count_slots_changed() {
+    DebugApi::dummy();
+
+    let previous_attributes = EquippableAttributes::<DebugApi>::new(&[
+        Item {
+            slot: managed_buffer!(b"hat"),
+            name: managed_buffer!(b"Pirate Hat"),
+        },
+        Item {
+            slot: managed_buffer!(b"background"),
+            name: managed_buffer!(b"Sky"),
+        },
+    ]);
+
+    let mut attributes = previous_attributes.clone();
+    attributes.empty_slot(&managed_buffer!(b"hat"));
+    attributes.set_item(
+        &managed_buffer!(b"beak"),
+        Option::Some(managed_buffer!(b"Gold")),
+    );
+
+    assert_eq!(
+        count_changed_slots(&previous_attributes, &previous_attributes),
+        0
+    );
+    assert_eq!(count_changed_slots(&previous_attributes, &attributes), 2);
+}
+
+fn prepare_hat<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+}
+
+fn set_egld_fee_schedule<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    flat: u64,
+    per_slot: u64,
+    per_item: u64,
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_fee_schedule(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    BigUint::from(flat),
+                    BigUint::from(per_slot),
+                    BigUint::from(per_item),
+                );
+            },
+        )
+        .assert_ok();
+}
+
+fn set_fee_beneficiaries<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    beneficiaries: &[(&Address, u32)],
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+            for (address, share) in beneficiaries {
+                args.push(MultiValue2::from((
+                    ManagedAddress::from_address(address),
+                    *share,
+                )));
+            }
+
+            sc.set_fee_beneficiaries(args);
+        },
+    );
+}
+
+fn deposit_egld_fee_credit<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    amount: u64,
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.first_user_address, &rust_biguint!(amount));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(amount),
+            |sc| {
+                sc.deposit_fee_credit();
+            },
+        )
+        .assert_ok();
+}
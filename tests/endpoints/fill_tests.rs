@@ -1,7 +1,9 @@
-use crate::testing_utils::{self, TestItemAttributes};
+use crate::testing_utils::{self, TestItemAttributes, EQUIPPABLE_TOKEN_ID};
 use customize_nft::{
-    constants::ERR_CANNOT_FILL_UNREGISTERED_ITEM, libs::storage::StorageModule,
-    structs::item::Item, EndpointWrappers, Equip,
+    constants::{ERR_CANNOT_FILL_UNREGISTERED_ITEM, ERR_NOT_A_SUPPLIER},
+    libs::storage::StorageModule,
+    structs::item::Item,
+    EndpointWrappers, Equip,
 };
 use elrond_wasm::{elrond_codec::multi_types::MultiValue4, types::MultiValueEncoded};
 use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint};
@@ -41,14 +43,17 @@ fn works_if_is_the_owner() {
                     TOKEN_NONCE,
                 )));
 
-                sc.register_item(items);
+                sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
 
                 sc.call_fill();
 
-                let opt_token = sc.get_token(&Item {
-                    name: managed_buffer!(TOKEN_NAME),
-                    slot: managed_buffer!(TOKEN_SLOT),
-                });
+                let opt_token = sc.get_token(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &Item {
+                        name: managed_buffer!(TOKEN_NAME),
+                        slot: managed_buffer!(TOKEN_SLOT),
+                    },
+                );
 
                 match opt_token {
                     Some(token) => {
@@ -97,7 +102,7 @@ fn panic_if_not_registered() {
 }
 
 #[test]
-fn panic_if_not_the_owner() {
+fn panic_if_not_the_owner_nor_a_supplier() {
     const TOKEN_ID: &[u8] = b"ITEM-a1a1a1";
     const TOKEN_NONCE: u64 = 654;
 
@@ -124,5 +129,5 @@ fn panic_if_not_the_owner() {
                 sc.call_fill();
             },
         )
-        .assert_user_error("Endpoint can only be called by owner");
+        .assert_user_error(ERR_NOT_A_SUPPLIER);
 }
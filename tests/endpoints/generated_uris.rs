@@ -0,0 +1,170 @@
+use std::ops::Deref;
+
+use customize_nft::constants::{ERR_GENERATED_URI_NOT_FOUND, ERR_GENERATED_URI_PREFIX_EMPTY};
+use customize_nft::libs::customization::CustomizationModule;
+use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::uris::UrisModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use customize_nft::utils::managed_buffer_utils::ManagedBufferUtils;
+use elrond_wasm::contract_base::ContractBase;
+use elrond_wasm::types::{ManagedAddress, ManagedBuffer, ManagedVec};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+/// sha256("hat:Pirate Hat")
+const PIRATE_HAT_HASH: &[u8] = b"48eace7e3e2fa7689646841a7ba99bcf17a243e4ac27bf099b6e04da54ce8291";
+
+#[test]
+fn carry_over_uris_and_add_generated_ones() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(&[], b"https://ipfs.io/ipfs/original");
+    add_generated_uri(&mut setup, b"https://example.com/metadata/", b".json").assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let attributes = EquippableAttributes::<DebugApi>::new(&[Item {
+                slot: managed_buffer!(b"hat"),
+                name: managed_buffer!(b"Pirate Hat"),
+            }]);
+            let hash = sc.get_attributes_hash(&attributes);
+
+            // the image of the previous attributes is not necessarily the first URI
+            let mut previous_uris = ManagedVec::new();
+            previous_uris.push(managed_buffer!(b"https://example.com/license"));
+            previous_uris.push(managed_buffer!(b"https://ipfs.io/ipfs/original"));
+            previous_uris.push(managed_buffer!(b"https://example.com/metadata/stale.json"));
+
+            let uris = sc.build_equippable_uris(
+                &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                &attributes,
+                &managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                &hash,
+                &EquippableAttributes::<DebugApi>::empty(),
+                &previous_uris,
+            );
+
+            let mut metadata_uri = b"https://example.com/metadata/".to_vec();
+            metadata_uri.extend_from_slice(PIRATE_HAT_HASH);
+            metadata_uri.extend_from_slice(b".json");
+
+            assert_eq!(uris.len(), 3);
+            assert_eq!(
+                uris.get(0).deref(),
+                &managed_buffer!(b"https://ipfs.io/ipfs/hat")
+            );
+            assert_eq!(uris.get(1).deref(), &managed_buffer!(&metadata_uri));
+            assert_eq!(
+                uris.get(2).deref(),
+                &managed_buffer!(b"https://example.com/license")
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_hash_of_attributes_on_remint() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::empty(),
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    setup.assert_uris(
+        EQUIPPABLE_TOKEN_ID,
+        opt_new_nonce.unwrap(),
+        &[b"https://ipfs.io/ipfs/hat"],
+    );
+
+    let owner = setup.first_user_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let esdt_data = sc.blockchain().get_esdt_token_data(
+                &ManagedAddress::from_address(&owner),
+                &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                opt_new_nonce.unwrap(),
+            );
+
+            let mut hex_hash = ManagedBuffer::new();
+            hex_hash.append_hex(&esdt_data.hash);
+            assert_eq!(hex_hash, managed_buffer!(PIRATE_HAT_HASH));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_remove_unknown_generated_uri() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_generated_uri(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"https://example.com/metadata/"),
+                    managed_buffer!(b".json"),
+                );
+            },
+        )
+        .assert_user_error(ERR_GENERATED_URI_NOT_FOUND);
+}
+
+#[test]
+fn panic_if_prefix_is_empty() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    add_generated_uri(&mut setup, b"", b".json").assert_user_error(ERR_GENERATED_URI_PREFIX_EMPTY);
+}
+
+fn add_generated_uri<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    prefix: &[u8],
+    suffix: &[u8],
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.add_generated_uri(
+                managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                managed_buffer!(prefix),
+                managed_buffer!(suffix),
+            );
+        },
+    );
+}
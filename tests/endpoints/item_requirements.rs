@@ -0,0 +1,165 @@
+use customize_nft::constants::{
+    ERR_CANNOT_UNEQUIP_REQUIRED_ITEM, ERR_ITEM_REQUIREMENT_NOT_MET, UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::requirements::RequirementsModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::elrond_codec::multi_types::OptionalValue;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const EARS_ID: &[u8] = b"EARS-a1a1a1";
+const EARRING_ID: &[u8] = b"EARRING-a1a1a1";
+const HELMET_ID: &[u8] = b"HELMET-a1a1a1";
+const VISOR_ID: &[u8] = b"VISOR-a1a1a1";
+
+#[test]
+fn panic_if_requirement_not_equipped() {
+    let mut setup = setup_items();
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(EARRING_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"earring", b"Gold")], b"https://ipfs.io/ipfs/earring");
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (EARRING_ID, 1),
+    ]));
+
+    tx_result.assert_user_error(ERR_ITEM_REQUIREMENT_NOT_MET);
+}
+
+#[test]
+fn equip_any_item_of_required_slot_in_same_call() {
+    let mut setup = setup_items();
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(HELMET_ID, 1, 1);
+    setup.add_random_item_to_user(VISOR_ID, 1, 1);
+    setup.set_uri_of_items(
+        &[(b"helmet", b"Knight"), (b"visor", b"Black")],
+        b"https://ipfs.io/ipfs/knight",
+    );
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HELMET_ID, 1),
+        (VISOR_ID, 1),
+    ]));
+
+    tx_result.assert_ok();
+}
+
+#[test]
+fn panic_if_unequip_required_item() {
+    let mut setup = setup_items();
+
+    create_equippable_with_ears_and_earring(&mut setup);
+    setup.set_uri_of_items(
+        &[(b"ears", UNEQUIPPED_ITEM_NAME), (b"earring", b"Gold")],
+        b"https://ipfs.io/ipfs/earring",
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[b"ears"],
+    );
+
+    tx_result.assert_user_error(ERR_CANNOT_UNEQUIP_REQUIRED_ITEM);
+}
+
+#[test]
+fn unequip_required_item_with_its_dependents() {
+    let mut setup = setup_items();
+
+    create_equippable_with_ears_and_earring(&mut setup);
+    setup.set_uri_of_items(
+        &[
+            (b"ears", UNEQUIPPED_ITEM_NAME),
+            (b"earring", UNEQUIPPED_ITEM_NAME),
+        ],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[b"ears", b"earring"],
+    );
+
+    tx_result.assert_ok();
+}
+
+fn setup_items() -> EquipSetup<impl Fn() -> customize_nft::ContractObj<DebugApi> + Copy> {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"ears", b"Pointy", EARS_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"earring", b"Gold", EARRING_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"helmet", b"Knight", HELMET_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"visor", b"Black", VISOR_ID, 1, &TestItemAttributes {});
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_item_requirement(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"earring"),
+                    managed_buffer!(b"Gold"),
+                    managed_buffer!(b"ears"),
+                    OptionalValue::Some(managed_buffer!(b"Pointy")),
+                );
+
+                sc.add_item_requirement(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"visor"),
+                    managed_buffer!(b"Black"),
+                    managed_buffer!(b"helmet"),
+                    OptionalValue::None,
+                );
+
+                assert_eq!(
+                    sc.get_item_requirements(
+                        managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        managed_buffer!(b"visor"),
+                        managed_buffer!(b"Black")
+                    )
+                    .len(),
+                    1
+                );
+            },
+        )
+        .assert_ok();
+
+    return setup;
+}
+
+fn create_equippable_with_ears_and_earring<CrowdfundingObjBuilder>(
+    setup: &mut EquipSetup<CrowdfundingObjBuilder>,
+) where
+    CrowdfundingObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::new(&[
+            Item {
+                slot: managed_buffer!(b"ears"),
+                name: managed_buffer!(b"Pointy"),
+            },
+            Item {
+                slot: managed_buffer!(b"earring"),
+                name: managed_buffer!(b"Gold"),
+            },
+        ]),
+    );
+}
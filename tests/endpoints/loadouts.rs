@@ -0,0 +1,225 @@
+use customize_nft::constants::{
+    ERR_LOADOUT_NOT_FOUND, ERR_LOADOUT_PAYMENT_NOT_USED, UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::loadouts::LoadoutsModule;
+use customize_nft::libs::vault::VaultModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm_debug::tx_mock::{TxInputESDT, TxResult};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const PIRATE_HAT_ID: &[u8] = b"HAT-a1a1a1";
+const COWBOY_HAT_ID: &[u8] = b"HAT-b2b2b2";
+const SCARF_ID: &[u8] = b"SCARF-c3c3c3";
+
+const LOADOUT_NAME: &[u8] = b"winter";
+
+#[test]
+fn apply_loadout_with_items_sent_and_from_vault() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup_winter_loadout(&mut setup);
+
+    setup.add_random_item_to_user(COWBOY_HAT_ID, 1, 1);
+    setup.add_random_item_to_user(SCARF_ID, 1, 1);
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            SCARF_ID,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.deposit_to_vault();
+            },
+        )
+        .assert_ok();
+
+    let (opt_new_nonce, tx_result) = apply_loadout(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+            (COWBOY_HAT_ID, 1),
+        ]),
+        LOADOUT_NAME,
+    );
+    tx_result.assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        opt_new_nonce.unwrap(),
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::new(&[
+            Item {
+                slot: managed_buffer!(b"hat"),
+                name: managed_buffer!(b"Cowboy Hat"),
+            },
+            Item {
+                slot: managed_buffer!(b"scarf"),
+                name: managed_buffer!(b"Wool Scarf"),
+            },
+        ])),
+    );
+
+    // the displaced hat is sent back
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, PIRATE_HAT_ID, 1),
+        rust_biguint!(1)
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let owner = elrond_wasm::types::ManagedAddress::from_address(&setup.first_user_address);
+            assert_eq!(sc.get_vault(owner).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_item_missing() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup_winter_loadout(&mut setup);
+    setup.add_random_item_to_user(COWBOY_HAT_ID, 1, 1);
+
+    let (_, tx_result) = apply_loadout(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+            (COWBOY_HAT_ID, 1),
+        ]),
+        LOADOUT_NAME,
+    );
+    tx_result.assert_user_error(
+        "The item Wool Scarf of the slot scarf is missing. Send it, or deposit it in your vault.",
+    );
+}
+
+#[test]
+fn panic_if_item_sent_is_not_needed() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup_winter_loadout(&mut setup);
+    setup.add_random_item_to_user(COWBOY_HAT_ID, 1, 1);
+    setup.add_random_item_to_user(SCARF_ID, 1, 1);
+    setup.add_random_item_to_user(PIRATE_HAT_ID, 1, 1);
+
+    let (_, tx_result) = apply_loadout(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+            (COWBOY_HAT_ID, 1),
+            (SCARF_ID, 1),
+            (PIRATE_HAT_ID, 1),
+        ]),
+        LOADOUT_NAME,
+    );
+    tx_result.assert_user_error(ERR_LOADOUT_PAYMENT_NOT_USED);
+}
+
+#[test]
+fn panic_if_loadout_not_found() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+
+    let (_, tx_result) = apply_loadout(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        b"summer",
+    );
+    tx_result.assert_user_error(ERR_LOADOUT_NOT_FOUND);
+}
+
+/// The equippable wears a pirate hat, and the user saved a loadout with a cowboy hat and a scarf.
+fn setup_winter_loadout<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    DebugApi::dummy();
+
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        PIRATE_HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.register_and_fill_item(
+        b"hat",
+        b"Cowboy Hat",
+        COWBOY_HAT_ID,
+        1,
+        &TestItemAttributes {},
+    );
+    setup.register_and_fill_item(b"scarf", b"Wool Scarf", SCARF_ID, 1, &TestItemAttributes {});
+    setup.set_uri_of_items(
+        &[(b"hat", b"Cowboy Hat"), (b"scarf", b"Wool Scarf")],
+        b"https://ipfs.io/ipfs/winter",
+    );
+    setup.set_uri_of_items(
+        &[
+            (b"hat", UNEQUIPPED_ITEM_NAME),
+            (b"scarf", UNEQUIPPED_ITEM_NAME),
+        ],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.save_loadout(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(LOADOUT_NAME),
+                    EquippableAttributes::<DebugApi>::new(&[
+                        Item {
+                            slot: managed_buffer!(b"hat"),
+                            name: managed_buffer!(b"Cowboy Hat"),
+                        },
+                        Item {
+                            slot: managed_buffer!(b"scarf"),
+                            name: managed_buffer!(b"Wool Scarf"),
+                        },
+                    ]),
+                );
+            },
+        )
+        .assert_ok();
+}
+
+fn apply_loadout<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    transfers: Vec<TxInputESDT>,
+    name: &[u8],
+) -> (Option<u64>, TxResult)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let mut opt_sc_result = Option::None;
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_multi_transfer(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        &transfers,
+        |sc| {
+            opt_sc_result = Option::Some(sc.apply_loadout(managed_buffer!(name)));
+        },
+    );
+
+    return (opt_sc_result, tx_result);
+}
@@ -0,0 +1,170 @@
+use customize_nft::constants::{
+    ERR_LEGACY_EQUIPPED_ABOVE_BALANCE, ERR_LEGACY_INVENTORY_MIGRATION_CLOSED,
+};
+use customize_nft::libs::inventory::{EndpointWrappers, InventoryModule};
+use customize_nft::libs::vault::VaultModule;
+use customize_nft::structs::token::Token;
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::{BigUint, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_NONCE: u64 = 1;
+
+#[test]
+fn works() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(
+        b"hat",
+        b"Pirate Hat",
+        HAT_ID,
+        HAT_NONCE,
+        &TestItemAttributes {},
+    );
+    setup.add_random_item_to_user(HAT_ID, HAT_NONCE, 2);
+
+    // a user keeps two hats in the vault
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            HAT_ID,
+            HAT_NONCE,
+            &rust_biguint!(2),
+            |sc| {
+                sc.deposit_to_vault();
+            },
+        )
+        .assert_ok();
+
+    clear_ledger(&mut setup);
+
+    migrate(&mut setup, 1).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let inventory = sc.get_inventory_of(&Token::new(managed_token_id!(HAT_ID), HAT_NONCE));
+
+            assert_eq!(inventory.deposited, BigUint::from(1u32));
+            assert_eq!(inventory.equipped, BigUint::from(1u32));
+            assert_eq!(inventory.stock, BigUint::from(2u32));
+            assert_eq!(inventory.get_available(), BigUint::from(1u32));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_migrated_twice() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(
+        b"hat",
+        b"Pirate Hat",
+        HAT_ID,
+        HAT_NONCE,
+        &TestItemAttributes {},
+    );
+    clear_ledger(&mut setup);
+
+    migrate(&mut setup, 0).assert_ok();
+    migrate(&mut setup, 0).assert_user_error(ERR_LEGACY_INVENTORY_MIGRATION_CLOSED);
+}
+
+#[test]
+fn panic_if_migrated_after_a_deposit() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(
+        b"hat",
+        b"Pirate Hat",
+        HAT_ID,
+        HAT_NONCE,
+        &TestItemAttributes {},
+    );
+
+    migrate(&mut setup, 0).assert_user_error(ERR_LEGACY_INVENTORY_MIGRATION_CLOSED);
+}
+
+#[test]
+fn panic_if_equipped_above_balance() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(
+        b"hat",
+        b"Pirate Hat",
+        HAT_ID,
+        HAT_NONCE,
+        &TestItemAttributes {},
+    );
+    clear_ledger(&mut setup);
+
+    migrate(&mut setup, 3).assert_user_error(ERR_LEGACY_EQUIPPED_ABOVE_BALANCE);
+}
+
+#[test]
+fn panic_if_not_the_owner() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.call_migrate_legacy_inventory();
+            },
+        )
+        .assert_user_error("Endpoint can only be called by owner");
+}
+
+/// The tokens were sent before the ledger was kept.
+fn clear_ledger<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.inventory(&Token::new(managed_token_id!(HAT_ID), HAT_NONCE))
+                    .clear();
+                sc.inventory_ledger_started().clear();
+            },
+        )
+        .assert_ok();
+}
+
+fn migrate<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    equipped: u64,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut tokens = MultiValueEncoded::new();
+            tokens.push(MultiValue3::from((
+                managed_token_id!(HAT_ID),
+                HAT_NONCE,
+                BigUint::from(equipped),
+            )));
+
+            sc.migrate_legacy_inventory(tokens);
+        },
+    );
+}
@@ -0,0 +1,95 @@
+use customize_nft::constants::ERR_CANNOT_OVERRIDE_REGISTERED_ITEM;
+use customize_nft::libs::storage::StorageModule;
+use customize_nft::structs::item::Item;
+use customize_nft::structs::token::Token;
+use customize_nft::{EndpointWrappers, Equip};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
+const SLOT: &[u8] = b"hat";
+const ITEM_NAME: &[u8] = b"Pirate Hat";
+const ITEM_ID: &[u8] = b"HAT-a1a1a1";
+const ITEM_NONCE: u64 = 1;
+
+#[test]
+fn works() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let item = Item {
+                    slot: managed_buffer!(SLOT),
+                    name: managed_buffer!(ITEM_NAME),
+                };
+                let token = Token::new(managed_token_id!(ITEM_ID), ITEM_NONCE);
+
+                sc.legacy_items_tokens().insert(item.clone(), token.clone());
+
+                sc.migrate_legacy_items(managed_token_id!(EQUIPPABLE_TOKEN_ID));
+
+                assert_eq!(
+                    sc.get_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &token),
+                    Option::Some(item.clone())
+                );
+                assert_eq!(
+                    sc.get_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item),
+                    Option::Some(token)
+                );
+                assert_eq!(sc.legacy_items_tokens().is_empty(), true);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_token_already_registered() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, ITEM_NONCE, &TestItemAttributes {});
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.legacy_items_tokens().insert(
+                    Item {
+                        slot: managed_buffer!(SLOT),
+                        name: managed_buffer!(ITEM_NAME),
+                    },
+                    Token::new(managed_token_id!(ITEM_ID), ITEM_NONCE),
+                );
+
+                sc.migrate_legacy_items(managed_token_id!(EQUIPPABLE_TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_CANNOT_OVERRIDE_REGISTERED_ITEM);
+}
+
+#[test]
+fn panic_if_not_the_owner() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.call_migrate_legacy_items();
+            },
+        )
+        .assert_user_error("Endpoint can only be called by owner");
+}
@@ -0,0 +1,73 @@
+use customize_nft::libs::equippable_uris::EndpointWrappers;
+use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::uris::UrisModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils;
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
+const NAME_TO_RENDER: &[u8] = b"Equippable #1";
+const NAME_RENDERED: &[u8] = b"Equippable #2";
+const URI: &[u8] = b"https://ipfs.io/ipfs/rendered.png";
+
+#[test]
+fn works() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let attributes = EquippableAttributes::<DebugApi>::empty();
+
+                sc.legacy_equippable_token_id()
+                    .set(&managed_token_id!(EQUIPPABLE_TOKEN_ID));
+                sc.legacy_attributes_to_render_by_name()
+                    .insert(managed_buffer!(NAME_TO_RENDER), attributes.clone());
+                sc.legacy_attributes_to_render_by_name()
+                    .insert(managed_buffer!(NAME_RENDERED), attributes.clone());
+                sc.legacy_uris_of_attributes(&attributes, &managed_buffer!(NAME_RENDERED))
+                    .set(&managed_buffer!(URI));
+
+                sc.migrate_legacy_render_queue();
+
+                let queue =
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID));
+                assert_eq!(queue.len(), 1);
+                assert_eq!(queue.contains_key(&managed_buffer!(NAME_TO_RENDER)), true);
+                assert_eq!(sc.legacy_attributes_to_render_by_name().is_empty(), true);
+
+                assert_eq!(
+                    sc.get_uri_of(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &attributes,
+                        &managed_buffer!(NAME_RENDERED)
+                    ),
+                    managed_buffer!(URI)
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_not_the_owner() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.call_migrate_legacy_render_queue();
+            },
+        )
+        .assert_user_error("Endpoint can only be called by owner");
+}
@@ -1,9 +1,37 @@
 mod claim;
+mod consumable_items;
+mod cooldowns;
+mod customize_batch;
 mod customize_equip_tests;
 mod customize_tests;
 mod customize_unequip_tests;
+mod equippable_collections;
+mod escrow;
+mod exclusion_groups;
+mod fees;
 mod fill_tests;
+mod generated_uris;
 mod init;
+mod item_requirements;
+mod loadouts;
+mod migrate_legacy_inventory;
+mod migrate_legacy_items;
+mod migrate_legacy_render_queue;
 mod override_royalties;
+mod pause;
+mod placeholder_uri;
+mod proxies;
 mod register_item_tests;
+mod remap_item;
 mod render_image;
+mod set_preferred_token;
+mod slot_defaults;
+mod slot_locks;
+mod slots;
+mod suppliers;
+mod supply_mode;
+mod swap;
+mod unregister_item;
+mod update_mode;
+mod vault;
+mod withdraw_items;
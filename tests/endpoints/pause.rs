@@ -0,0 +1,172 @@
+use std::ops::Deref;
+
+use customize_nft::constants::{
+    ENQUEUE_PRICE, ERR_CONTRACT_PAUSED, ERR_ENDPOINT_NOT_PAUSABLE, ERR_ENDPOINT_PAUSED,
+    ERR_NOT_OWNER_OR_PAUSER,
+};
+use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::pause::{EndpointWrappers, PauseModule};
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use elrond_wasm::types::{Address, ManagedAddress, ManagedBuffer, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn pause_and_unpause_customize() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+
+    let owner = setup.owner_address.clone();
+    pause(&mut setup, &owner).assert_ok();
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_user_error(ERR_CONTRACT_PAUSED);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unpause();
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+}
+
+#[test]
+fn pauser_pauses_render_image() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let pauser = setup.second_user_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_pauser(ManagedAddress::from_address(&pauser));
+            },
+        )
+        .assert_ok();
+
+    pause_endpoint(&mut setup, &pauser, b"renderImage").assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let (paused, paused_endpoints) = sc.get_pause_state().into_tuple();
+
+            assert_eq!(paused, false);
+            assert_eq!(paused_endpoints.len(), 1);
+            assert_eq!(
+                paused_endpoints.get(0).deref(),
+                &managed_buffer!(b"renderImage")
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.first_user_address, &rust_biguint!(ENQUEUE_PRICE));
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(ENQUEUE_PRICE),
+            |sc| {
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &EquippableAttributes::<DebugApi>::empty(),
+                    &managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                );
+            },
+        )
+        .assert_user_error(ERR_ENDPOINT_PAUSED);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&pauser, &setup.cf_wrapper, &rust_biguint!(0), |sc| {
+            sc.call_unpause();
+        })
+        .assert_user_error("Endpoint can only be called by owner");
+}
+
+#[test]
+fn panic_if_not_pauser() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let user = setup.first_user_address.clone();
+    pause(&mut setup, &user).assert_user_error(ERR_NOT_OWNER_OR_PAUSER);
+}
+
+#[test]
+fn panic_if_endpoint_not_pausable() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let owner = setup.owner_address.clone();
+    pause_endpoint(&mut setup, &owner, b"withdrawFromVault")
+        .assert_user_error(ERR_ENDPOINT_NOT_PAUSABLE);
+}
+
+fn pause<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    caller: &Address,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        caller,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.pause();
+        },
+    );
+}
+
+fn pause_endpoint<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    caller: &Address,
+    endpoint: &[u8],
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        caller,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut endpoints = MultiValueEncoded::<DebugApi, ManagedBuffer<DebugApi>>::new();
+            endpoints.push(managed_buffer!(endpoint));
+
+            sc.pause_endpoints(endpoints);
+        },
+    );
+}
@@ -0,0 +1,285 @@
+use customize_nft::constants::{
+    ERR_ENDPOINT_PAUSED, ERR_IMAGE_NOT_RENDERED_YET, ERR_NO_PLACEHOLDER_TO_REPLACE,
+};
+use customize_nft::libs::customize::CustomizeModule;
+use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::pause::PauseModule;
+use customize_nft::libs::uris::UrisModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::types::{ManagedBuffer, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::args_set_cid_of;
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+const PLACEHOLDER_URI: &[u8] = b"https://ipfs.io/ipfs/rendering";
+const HAT_URI: &[u8] = b"https://ipfs.io/ipfs/hat";
+
+#[test]
+fn customize_with_placeholder_then_refresh() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let new_nonce = customize_with_placeholder(&mut setup);
+
+    setup.assert_uris(EQUIPPABLE_TOKEN_ID, new_nonce, &[PLACEHOLDER_URI]);
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .get(&managed_buffer!(EQUIPPABLE_TOKEN_ID)),
+                Option::Some(get_hat_attributes())
+            );
+            assert_eq!(
+                sc.placeholder_nonces(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .contains(&new_nonce),
+                true
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    get_hat_attributes(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(HAT_URI)
+                ));
+            },
+        )
+        .assert_ok();
+
+    let (opt_refreshed_nonce, tx_result) = refresh_uri(&mut setup, new_nonce);
+    tx_result.assert_ok();
+
+    let refreshed_nonce = opt_refreshed_nonce.unwrap();
+    assert_ne!(refreshed_nonce, new_nonce);
+    setup.assert_uris(EQUIPPABLE_TOKEN_ID, refreshed_nonce, &[HAT_URI]);
+    setup.assert_is_burn(EQUIPPABLE_TOKEN_ID, new_nonce);
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.placeholder_nonces(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .is_empty(),
+                true
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_refresh_before_render() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let new_nonce = customize_with_placeholder(&mut setup);
+
+    let (_, tx_result) = refresh_uri(&mut setup, new_nonce);
+    tx_result.assert_user_error(ERR_IMAGE_NOT_RENDERED_YET);
+}
+
+#[test]
+fn panic_if_refresh_without_placeholder() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+
+    let (_, tx_result) = refresh_uri(&mut setup, EQUIPPABLE_NONCE);
+    tx_result.assert_user_error(ERR_NO_PLACEHOLDER_TO_REPLACE);
+}
+
+#[test]
+fn panic_if_refresh_while_paused() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let new_nonce = customize_with_placeholder(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    get_hat_attributes(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(HAT_URI)
+                ));
+
+                let mut endpoints = MultiValueEncoded::<DebugApi, ManagedBuffer<DebugApi>>::new();
+                endpoints.push(managed_buffer!(b"refreshUri"));
+                sc.pause_endpoints(endpoints);
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = refresh_uri(&mut setup, new_nonce);
+    tx_result.assert_user_error(ERR_ENDPOINT_PAUSED);
+}
+
+#[test]
+fn enqueue_waiting_render_once_the_queued_one_is_rendered() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .insert(
+                        managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                        EquippableAttributes::<DebugApi>::empty(),
+                    );
+            },
+        )
+        .assert_ok();
+
+    let new_nonce = customize_with_placeholder(&mut setup);
+
+    setup.assert_uris(EQUIPPABLE_TOKEN_ID, new_nonce, &[PLACEHOLDER_URI]);
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .get(&managed_buffer!(EQUIPPABLE_TOKEN_ID)),
+                Option::Some(EquippableAttributes::<DebugApi>::empty())
+            );
+        })
+        .assert_ok();
+
+    // rendering the queued image enqueues the attributes of the equippable
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    EquippableAttributes::<DebugApi>::empty(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"https://ipfs.io/ipfs/empty")
+                ));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .get(&managed_buffer!(EQUIPPABLE_TOKEN_ID)),
+                Option::Some(get_hat_attributes())
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_of_attributes(args_set_cid_of!(
+                    get_hat_attributes(),
+                    managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(HAT_URI)
+                ));
+            },
+        )
+        .assert_ok();
+
+    let (opt_refreshed_nonce, tx_result) = refresh_uri(&mut setup, new_nonce);
+    tx_result.assert_ok();
+
+    setup.assert_uris(
+        EQUIPPABLE_TOKEN_ID,
+        opt_refreshed_nonce.unwrap(),
+        &[HAT_URI],
+    );
+}
+
+fn get_hat_attributes() -> EquippableAttributes<DebugApi> {
+    return EquippableAttributes::<DebugApi>::new(&[Item {
+        slot: managed_buffer!(b"hat"),
+        name: managed_buffer!(b"Pirate Hat"),
+    }]);
+}
+
+/// Returns the nonce of the customized equippable.
+fn customize_with_placeholder<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+) -> u64
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_placeholder_uri(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(PLACEHOLDER_URI),
+                );
+            },
+        )
+        .assert_ok();
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    return opt_new_nonce.unwrap();
+}
+
+fn refresh_uri<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    nonce: u64,
+) -> (Option<u64>, TxResult)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let mut opt_new_nonce = Option::None;
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_transfer(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        EQUIPPABLE_TOKEN_ID,
+        nonce,
+        &rust_biguint!(1),
+        |sc| {
+            opt_new_nonce = Option::Some(sc.refresh_uri());
+        },
+    );
+
+    return (opt_new_nonce, tx_result);
+}
@@ -0,0 +1,174 @@
+use customize_nft::constants::{
+    ERR_INVALID_RECEIVER, ERR_NOT_ALLOWED_TO_SET_RECEIVER, ERR_PROXY_NOT_FOUND,
+    UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::proxies::ProxiesModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use elrond_wasm::types::{Address, ManagedAddress, ManagedBuffer, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_NONCE: u64 = 1;
+
+#[test]
+fn proxy_delivers_to_receiver() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        HAT_ID,
+        HAT_NONCE,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let proxy = setup.first_user_address.clone();
+    add_proxy(&mut setup, &proxy).assert_ok();
+
+    let receiver = setup.second_user_address.clone();
+    let (opt_new_nonce, tx_result) = customize_for(&mut setup, &receiver, &[b"hat"]);
+    tx_result.assert_ok();
+
+    DebugApi::dummy();
+    setup
+        .blockchain_wrapper
+        .check_nft_balance::<EquippableAttributes<DebugApi>>(
+            &setup.second_user_address,
+            EQUIPPABLE_TOKEN_ID,
+            opt_new_nonce.unwrap(),
+            &rust_biguint!(1),
+            Option::None,
+        );
+    setup
+        .blockchain_wrapper
+        .check_nft_balance::<TestItemAttributes>(
+            &setup.second_user_address,
+            HAT_ID,
+            HAT_NONCE,
+            &rust_biguint!(1),
+            Option::None,
+        );
+    setup
+        .blockchain_wrapper
+        .check_nft_balance::<TestItemAttributes>(
+            &setup.first_user_address,
+            HAT_ID,
+            HAT_NONCE,
+            &rust_biguint!(0),
+            Option::None,
+        );
+}
+
+#[test]
+fn panic_if_not_proxy_sets_another_receiver() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        HAT_ID,
+        HAT_NONCE,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+
+    let receiver = setup.second_user_address.clone();
+    let (_, tx_result) = customize_for(&mut setup, &receiver, &[b"hat"]);
+
+    tx_result.assert_user_error(ERR_NOT_ALLOWED_TO_SET_RECEIVER);
+}
+
+#[test]
+fn panic_if_receiver_is_zero_address() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        HAT_ID,
+        HAT_NONCE,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+
+    let proxy = setup.first_user_address.clone();
+    add_proxy(&mut setup, &proxy).assert_ok();
+
+    let (_, tx_result) = customize_for(&mut setup, &Address::zero(), &[b"hat"]);
+
+    tx_result.assert_user_error(ERR_INVALID_RECEIVER);
+}
+
+#[test]
+fn panic_if_remove_unknown_proxy() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let proxy = setup.first_user_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_proxy(ManagedAddress::from_address(&proxy));
+            },
+        )
+        .assert_user_error(ERR_PROXY_NOT_FOUND);
+}
+
+fn add_proxy<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    proxy: &Address,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.add_proxy(ManagedAddress::from_address(proxy));
+        },
+    );
+}
+
+fn customize_for<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    receiver: &Address,
+    unequip_slots: &[&[u8]],
+) -> (Option<u64>, TxResult)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let mut opt_new_nonce = Option::None;
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_multi_transfer(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        &testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        |sc| {
+            let mut slots = MultiValueEncoded::<DebugApi, ManagedBuffer<DebugApi>>::new();
+            for slot in unequip_slots {
+                slots.push(managed_buffer!(slot));
+            }
+
+            let new_nonce = sc.customize_for(ManagedAddress::from_address(receiver), slots);
+            opt_new_nonce = Option::Some(new_nonce);
+        },
+    );
+
+    return (opt_new_nonce, tx_result);
+}
@@ -16,7 +16,7 @@ use elrond_wasm_debug::tx_mock::TxResult;
 use elrond_wasm_debug::{managed_buffer, managed_token_id};
 use elrond_wasm_debug::{rust_biguint, DebugApi};
 
-use crate::testing_utils::{self, TestItemAttributes};
+use crate::testing_utils::{self, TestItemAttributes, EQUIPPABLE_TOKEN_ID};
 
 #[test]
 fn test_register_item() {
@@ -40,10 +40,10 @@ fn test_register_item() {
     setup
         .blockchain_wrapper
         .execute_query(&setup.cf_wrapper, |sc| {
-            let result = sc.get_item(&Token::new(
-                TokenIdentifier::from_esdt_bytes(TOKEN_ID),
-                TOKEN_NONCE,
-            ));
+            let result = sc.get_item(
+                &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                &Token::new(TokenIdentifier::from_esdt_bytes(TOKEN_ID), TOKEN_NONCE),
+            );
 
             assert_eq!(result.is_some(), true);
             assert_eq!(
@@ -92,10 +92,10 @@ fn register_another_item_on_slot() {
         .blockchain_wrapper
         .execute_query(&setup.cf_wrapper, |sc| {
             assert_eq!(
-                sc.get_item(&Token::new(
-                    managed_token_id!(FIRST_TOKEN_ID),
-                    FIRST_TOKEN_NONCE
-                ))
+                sc.get_item(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &Token::new(managed_token_id!(FIRST_TOKEN_ID), FIRST_TOKEN_NONCE)
+                )
                 .unwrap(),
                 Item {
                     slot: managed_buffer!(COMMON_SLOT),
@@ -104,10 +104,10 @@ fn register_another_item_on_slot() {
             );
 
             assert_eq!(
-                sc.get_item(&Token::new(
-                    managed_token_id!(SECOND_TOKEN_ID),
-                    SECOND_TOKEN_NONCE
-                ))
+                sc.get_item(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &Token::new(managed_token_id!(SECOND_TOKEN_ID), SECOND_TOKEN_NONCE)
+                )
                 .unwrap(),
                 Item {
                     slot: managed_buffer!(COMMON_SLOT),
@@ -146,7 +146,7 @@ fn panic_if_override() {
                     TOKEN_NONCE,
                 )));
 
-                sc.register_item(items);
+                sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
             },
         )
         .assert_ok();
@@ -166,7 +166,7 @@ fn panic_if_override() {
                     TOKEN_NONCE,
                 )));
 
-                sc.register_item(items);
+                sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
             },
         )
         .assert_user_error(ERR_CANNOT_OVERRIDE_REGISTERED_ITEM);
@@ -193,7 +193,7 @@ fn panic_if_register_equippable() {
                     1,
                 )));
 
-                sc.register_item(items);
+                sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
             },
         )
         .assert_user_error(ERR_CANNOT_REGISTER_EQUIPPABLE_AS_ITEM);
@@ -262,7 +262,7 @@ fn call_register_item(slot: &[u8], name: &[u8], token_id: &[u8]) -> TxResult {
                 1,
             )));
 
-            sc.register_item(items);
+            sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
         },
     );
 }
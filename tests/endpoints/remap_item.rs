@@ -0,0 +1,312 @@
+use customize_nft::constants::{ERR_CANNOT_REGISTER_REMAPPED_ITEM, UNEQUIPPED_ITEM_NAME};
+use customize_nft::libs::requirements::RequirementsModule;
+use customize_nft::libs::storage::StorageModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use customize_nft::structs::item_requirement::ItemRequirement;
+use customize_nft::structs::registry_change::RegistryChangeKind;
+use customize_nft::Equip;
+use elrond_wasm::elrond_codec::multi_types::{MultiValue4, OptionalValue};
+use elrond_wasm::types::MultiValueEncoded;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"hat";
+const TYPO_NAME: &[u8] = b"Pirat Hat";
+const FIXED_NAME: &[u8] = b"Pirate Hat";
+const ITEM_ID: &[u8] = b"HAT-a1a1a1";
+const ITEM_NONCE: u64 = 1;
+
+#[test]
+fn migrate_equippable_carrying_old_name() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        ITEM_ID,
+        ITEM_NONCE,
+        SLOT,
+        TestItemAttributes {},
+        TYPO_NAME,
+    );
+
+    remap(&mut setup, SLOT, TYPO_NAME, SLOT, FIXED_NAME).assert_ok();
+
+    const BG_SLOT: &[u8] = b"background";
+    const BG_NAME: &[u8] = b"Blue";
+    const BG_ID: &[u8] = b"BG-a1a1a1";
+    setup.register_and_fill_item(BG_SLOT, BG_NAME, BG_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(BG_ID, 1, 1);
+    setup.set_uri_of_items(
+        &[(SLOT, FIXED_NAME), (BG_SLOT, BG_NAME)],
+        b"https://ipfs.io/ipfs/fixed",
+    );
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (BG_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::new(&[
+            Item {
+                slot: managed_buffer!(SLOT),
+                name: managed_buffer!(FIXED_NAME),
+            },
+            Item {
+                slot: managed_buffer!(BG_SLOT),
+                name: managed_buffer!(BG_NAME),
+            },
+        ])),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let changes: Vec<_> = sc.get_registry_changes().into_iter().collect();
+
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].kind, RegistryChangeKind::Remap);
+            assert_eq!(
+                changes[0].new_item.clone().unwrap(),
+                Item {
+                    slot: managed_buffer!(SLOT),
+                    name: managed_buffer!(FIXED_NAME),
+                }
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn unequip_item_carrying_old_name() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        ITEM_ID,
+        ITEM_NONCE,
+        SLOT,
+        TestItemAttributes {},
+        TYPO_NAME,
+    );
+
+    remap(&mut setup, SLOT, TYPO_NAME, SLOT, FIXED_NAME).assert_ok();
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, ITEM_NONCE),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn unequip_item_moved_to_another_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        ITEM_ID,
+        ITEM_NONCE,
+        SLOT,
+        TestItemAttributes {},
+        TYPO_NAME,
+    );
+
+    remap(&mut setup, SLOT, TYPO_NAME, b"head", TYPO_NAME).assert_ok();
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    // the equippable keeps the old item, which can still be unequipped
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, ITEM_NONCE),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn move_requirements_to_new_name() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(SLOT, TYPO_NAME, ITEM_ID, ITEM_NONCE, &TestItemAttributes {});
+    setup.register_and_fill_item(
+        b"feather",
+        b"Red",
+        b"FEATHER-a1a1a1",
+        1,
+        &TestItemAttributes {},
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_item_requirement(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(TYPO_NAME),
+                    managed_buffer!(b"hair"),
+                    OptionalValue::None,
+                );
+                sc.add_item_requirement(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"feather"),
+                    managed_buffer!(b"Red"),
+                    managed_buffer!(SLOT),
+                    OptionalValue::Some(managed_buffer!(TYPO_NAME)),
+                );
+            },
+        )
+        .assert_ok();
+
+    remap(&mut setup, SLOT, TYPO_NAME, SLOT, FIXED_NAME).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let get_requirements = |slot: &[u8], name: &[u8]| -> Vec<_> {
+                sc.get_item_requirements(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(slot),
+                    managed_buffer!(name),
+                )
+                .into_iter()
+                .collect()
+            };
+
+            assert_eq!(
+                get_requirements(SLOT, FIXED_NAME),
+                vec![ItemRequirement {
+                    slot: managed_buffer!(b"hair"),
+                    name: Option::None,
+                }]
+            );
+            assert_eq!(get_requirements(SLOT, TYPO_NAME), vec![]);
+            assert_eq!(
+                get_requirements(b"feather", b"Red"),
+                vec![ItemRequirement {
+                    slot: managed_buffer!(SLOT),
+                    name: Option::Some(managed_buffer!(FIXED_NAME)),
+                }]
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_registering_old_name() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, TYPO_NAME, ITEM_ID, ITEM_NONCE, &TestItemAttributes {});
+
+    remap(&mut setup, SLOT, TYPO_NAME, SLOT, FIXED_NAME).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue4::from((
+                    managed_buffer!(SLOT),
+                    managed_buffer!(TYPO_NAME),
+                    managed_token_id!(b"HAT-b2b2b2"),
+                    1,
+                )));
+
+                sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
+            },
+        )
+        .assert_user_error(ERR_CANNOT_REGISTER_REMAPPED_ITEM);
+}
+
+#[test]
+fn panic_if_remapping_back_to_old_name() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, TYPO_NAME, ITEM_ID, ITEM_NONCE, &TestItemAttributes {});
+
+    remap(&mut setup, SLOT, TYPO_NAME, SLOT, FIXED_NAME).assert_ok();
+    remap(&mut setup, SLOT, FIXED_NAME, SLOT, TYPO_NAME)
+        .assert_user_error(ERR_CANNOT_REGISTER_REMAPPED_ITEM);
+}
+
+fn remap<CrowdfundingObjBuilder>(
+    setup: &mut testing_utils::EquipSetup<CrowdfundingObjBuilder>,
+    slot: &[u8],
+    name: &[u8],
+    new_slot: &[u8],
+    new_name: &[u8],
+) -> elrond_wasm_debug::tx_mock::TxResult
+where
+    CrowdfundingObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.remap_item(
+                managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                managed_buffer!(slot),
+                managed_buffer!(name),
+                managed_buffer!(new_slot),
+                managed_buffer!(new_name),
+                managed_token_id!(ITEM_ID),
+                ITEM_NONCE,
+            );
+
+            assert_eq!(
+                sc.get_token(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &Item {
+                        slot: managed_buffer!(new_slot),
+                        name: managed_buffer!(new_name),
+                    }
+                )
+                .is_some(),
+                true
+            );
+        },
+    )
+}
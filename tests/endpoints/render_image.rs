@@ -1,11 +1,17 @@
 use customize_nft::{
-    constants::{ENQUEUE_PRICE, ERR_PAY_0001_EGLD},
-    libs::equippable_uris::EquippableUrisModule,
+    constants::{ENQUEUE_PRICE, ERR_PAY_0001_EGLD, ERR_TREASURY_EMPTY, ERR_WRONG_RENDER_PAYMENT},
+    libs::{equippable_uris::EquippableUrisModule, treasury::TreasuryModule, uris::UrisModule},
     structs::equippable_attributes::EquippableAttributes,
 };
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm::types::{BigUint, EgldOrEsdtTokenIdentifier};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
-use crate::testing_utils;
+use crate::testing_utils::{self, EquipSetup};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
+const GAME_TOKEN_ID: &[u8] = b"GAME-a1a1a1";
+const GAME_PRICE: u64 = 250;
 
 #[test]
 fn works() {
@@ -25,10 +31,22 @@ fn works() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
             },
         )
         .assert_ok();
@@ -48,10 +66,22 @@ fn panic_if_dont_send_egld() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
             },
         )
         .assert_user_error(ERR_PAY_0001_EGLD);
@@ -75,10 +105,22 @@ fn panic_if_send_lesser_amount_of_egld() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
             },
         )
         .assert_user_error(ERR_PAY_0001_EGLD);
@@ -102,11 +144,154 @@ fn panic_if_send_greater_amount_of_egld() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
             },
         )
         .assert_user_error(ERR_PAY_0001_EGLD);
 }
+
+#[test]
+fn pay_in_accepted_esdt_and_claim_treasury() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    set_game_token_render_price(&mut setup);
+
+    setup.blockchain_wrapper.set_esdt_balance(
+        &setup.first_user_address,
+        GAME_TOKEN_ID,
+        &rust_biguint!(GAME_PRICE),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            GAME_TOKEN_ID,
+            0,
+            &rust_biguint!(GAME_PRICE),
+            |sc| {
+                let attributes = EquippableAttributes::<DebugApi>::empty();
+                let name = managed_buffer!(b"Equippable #512");
+
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
+
+                assert_eq!(
+                    sc.treasury(&EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                        GAME_TOKEN_ID
+                    )))
+                    .get(),
+                    BigUint::from(GAME_PRICE)
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_treasury(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                    GAME_TOKEN_ID
+                )));
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_esdt_balance(
+        &setup.owner_address,
+        GAME_TOKEN_ID,
+        &rust_biguint!(GAME_PRICE),
+    );
+}
+
+#[test]
+fn panic_if_claim_empty_treasury() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_treasury(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                    GAME_TOKEN_ID
+                )));
+            },
+        )
+        .assert_user_error(ERR_TREASURY_EMPTY);
+}
+
+#[test]
+fn panic_if_pay_egld_when_not_accepted() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    set_game_token_render_price(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.owner_address, &rust_biguint!(ENQUEUE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(ENQUEUE_PRICE),
+            |sc| {
+                let attributes = EquippableAttributes::<DebugApi>::empty();
+                let name = managed_buffer!(b"Equippable #512");
+
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
+            },
+        )
+        .assert_user_error(ERR_WRONG_RENDER_PAYMENT);
+}
+
+fn set_game_token_render_price<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_render_price(
+                    EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(GAME_TOKEN_ID)),
+                    BigUint::from(GAME_PRICE),
+                );
+            },
+        )
+        .assert_ok();
+}
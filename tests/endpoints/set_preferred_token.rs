@@ -0,0 +1,208 @@
+use customize_nft::constants::ERR_TOKEN_DOES_NOT_REPRESENT_ITEM;
+use customize_nft::libs::inventory::InventoryModule;
+use customize_nft::Equip;
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::MultiValueEncoded;
+use elrond_wasm_debug::{
+    managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+
+use crate::testing_utils::{self, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"hat";
+const ITEM_NAME: &[u8] = b"Pirate Hat";
+const ORIGINAL_ITEM_ID: &[u8] = b"HAT-a1a1a1";
+const COLLAB_ITEM_ID: &[u8] = b"COLLAB-b2b2b2";
+const ITEM_NONCE: u64 = 1;
+
+#[test]
+fn equip_any_token_and_unequip_preferred_one() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(
+        SLOT,
+        ITEM_NAME,
+        ORIGINAL_ITEM_ID,
+        ITEM_NONCE,
+        &TestItemAttributes {},
+    );
+    setup.register_and_fill_item(
+        SLOT,
+        ITEM_NAME,
+        COLLAB_ITEM_ID,
+        ITEM_NONCE,
+        &TestItemAttributes {},
+    );
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(COLLAB_ITEM_ID, ITEM_NONCE, 1);
+    setup.set_uri_of_items(&[(SLOT, ITEM_NAME)], b"https://ipfs.io/ipfs/hat");
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (COLLAB_ITEM_ID, ITEM_NONCE),
+    ]));
+    tx_result.assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (token_id, nonce) = sc
+                    .get_preferred_token(
+                        managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        managed_buffer!(SLOT),
+                        managed_buffer!(ITEM_NAME),
+                    )
+                    .into_tuple();
+                assert_eq!(token_id, managed_token_id!(ORIGINAL_ITEM_ID));
+                assert_eq!(nonce, ITEM_NONCE);
+
+                sc.set_preferred_token(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                    managed_token_id!(COLLAB_ITEM_ID),
+                    ITEM_NONCE,
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.set_uri_of_items(
+        &[(SLOT, customize_nft::constants::UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_new_nonce.unwrap())]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_esdt_balance(
+            &setup.first_user_address,
+            COLLAB_ITEM_ID,
+            ITEM_NONCE
+        ),
+        rust_biguint!(1)
+    );
+    assert_eq!(
+        setup.blockchain_wrapper.get_esdt_balance(
+            &setup.first_user_address,
+            ORIGINAL_ITEM_ID,
+            ITEM_NONCE
+        ),
+        rust_biguint!(0)
+    );
+}
+
+#[test]
+fn unequip_another_token_if_preferred_one_is_out_of_stock() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(
+        SLOT,
+        ITEM_NAME,
+        ORIGINAL_ITEM_ID,
+        ITEM_NONCE,
+        &TestItemAttributes {},
+    );
+    setup.register_and_fill_item(
+        SLOT,
+        ITEM_NAME,
+        COLLAB_ITEM_ID,
+        ITEM_NONCE,
+        &TestItemAttributes {},
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue3::from((
+                    managed_token_id!(ORIGINAL_ITEM_ID),
+                    ITEM_NONCE,
+                    managed_biguint!(2),
+                )));
+
+                sc.withdraw_items(items);
+            },
+        )
+        .assert_ok();
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(COLLAB_ITEM_ID, ITEM_NONCE, 1);
+    setup.set_uri_of_items(&[(SLOT, ITEM_NAME)], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(SLOT, customize_nft::constants::UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (COLLAB_ITEM_ID, ITEM_NONCE),
+    ]));
+    tx_result.assert_ok();
+
+    // the preferred token is the original one, but none is left
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_new_nonce.unwrap())]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_esdt_balance(
+            &setup.first_user_address,
+            COLLAB_ITEM_ID,
+            ITEM_NONCE
+        ),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn panic_if_token_does_not_represent_item() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(
+        SLOT,
+        ITEM_NAME,
+        ORIGINAL_ITEM_ID,
+        ITEM_NONCE,
+        &TestItemAttributes {},
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_preferred_token(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                    managed_token_id!(COLLAB_ITEM_ID),
+                    ITEM_NONCE,
+                );
+            },
+        )
+        .assert_user_error(ERR_TOKEN_DOES_NOT_REPRESENT_ITEM);
+}
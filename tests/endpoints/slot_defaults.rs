@@ -0,0 +1,162 @@
+use customize_nft::constants::{ERR_CANNOT_UNEQUIP_MANDATORY_SLOT, ERR_SLOT_IS_NOT_MANDATORY};
+use customize_nft::libs::slots::SlotsModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"skin";
+const DEFAULT_NAME: &[u8] = b"Classic";
+const ITEM_NAME: &[u8] = b"Golden";
+const ITEM_ID: &[u8] = b"SKIN-a1a1a1";
+
+#[test]
+fn unequip_reverts_to_default() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        ITEM_ID,
+        1,
+        SLOT,
+        TestItemAttributes {},
+        ITEM_NAME,
+    );
+    set_slot_default(&mut setup);
+    setup.set_uri_of_items(&[(SLOT, DEFAULT_NAME)], b"https://ipfs.io/ipfs/classic");
+
+    let (opt_new_nonce, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, 1),
+        rust_biguint!(1)
+    );
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        opt_new_nonce.unwrap(),
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(SLOT),
+            name: managed_buffer!(DEFAULT_NAME),
+        }])),
+    );
+}
+
+#[test]
+fn equip_over_default_sends_nothing_back() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, 1, &TestItemAttributes {});
+    set_slot_default(&mut setup);
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(SLOT),
+            name: managed_buffer!(DEFAULT_NAME),
+        }]),
+    );
+    setup.add_random_item_to_user(ITEM_ID, 1, 1);
+    setup.set_uri_of_items(&[(SLOT, ITEM_NAME)], b"https://ipfs.io/ipfs/golden");
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (ITEM_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, 1),
+        rust_biguint!(0)
+    );
+}
+
+#[test]
+fn panic_if_unequip_default() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    set_slot_default(&mut setup);
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        EQUIPPABLE_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(SLOT),
+            name: managed_buffer!(DEFAULT_NAME),
+        }]),
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[SLOT],
+    );
+
+    tx_result.assert_user_error(ERR_CANNOT_UNEQUIP_MANDATORY_SLOT);
+}
+
+#[test]
+fn panic_if_remove_default_of_optional_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_slot_default(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                );
+            },
+        )
+        .assert_user_error(ERR_SLOT_IS_NOT_MANDATORY);
+}
+
+fn set_slot_default<CrowdfundingObjBuilder>(setup: &mut EquipSetup<CrowdfundingObjBuilder>)
+where
+    CrowdfundingObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_slot_default(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(DEFAULT_NAME),
+                );
+
+                assert_eq!(
+                    sc.get_slots_defaults(managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+            },
+        )
+        .assert_ok();
+}
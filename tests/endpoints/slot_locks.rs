@@ -0,0 +1,139 @@
+use customize_nft::constants::{PERMANENT_LOCK, UNEQUIPPED_ITEM_NAME};
+use customize_nft::Equip;
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"hat";
+const ITEM_NAME: &[u8] = b"Pirate Hat";
+const ITEM_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn cannot_unequip_before_unlock_time() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let new_nonce = equip_locked_item(&mut setup, 100);
+
+    assert_eq!(get_slot_unlock_time(&mut setup, new_nonce), 1100);
+
+    setup.blockchain_wrapper.set_block_timestamp(1050);
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, new_nonce)]),
+        &[SLOT],
+    );
+    tx_result.assert_user_error("The slot hat is locked.");
+
+    setup.blockchain_wrapper.set_block_timestamp(1100);
+    assert_eq!(get_slot_unlock_time(&mut setup, new_nonce), 0);
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, new_nonce)]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+}
+
+#[test]
+fn cannot_unequip_soulbound_item() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let new_nonce = equip_locked_item(&mut setup, PERMANENT_LOCK);
+
+    assert_eq!(get_slot_unlock_time(&mut setup, new_nonce), PERMANENT_LOCK);
+
+    setup.blockchain_wrapper.set_block_timestamp(u64::MAX - 1);
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, new_nonce)]),
+        &[SLOT],
+    );
+    tx_result.assert_user_error("The slot hat is locked.");
+}
+
+#[test]
+fn cannot_equip_over_locked_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    let new_nonce = equip_locked_item(&mut setup, 100);
+
+    setup.add_random_item_to_user(ITEM_ID, 1, 1);
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, new_nonce),
+        (ITEM_ID, 1),
+    ]));
+    tx_result.assert_user_error("The slot hat is locked.");
+}
+
+/// Equip an item locked for `duration` seconds at the timestamp 1000, and returns the new nonce of the equippable.
+fn equip_locked_item<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    duration: u64,
+) -> u64
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(ITEM_ID, 1, 1);
+    setup.set_uri_of_items(&[(SLOT, ITEM_NAME)], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_item_lock_duration(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                    duration,
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(1000);
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (ITEM_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    return opt_new_nonce.unwrap();
+}
+
+fn get_slot_unlock_time<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    nonce: u64,
+) -> u64
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let mut unlock_time = 0;
+    let owner = setup.first_user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            unlock_time = sc.get_slot_unlock_time(
+                ManagedAddress::from_address(&owner),
+                managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                nonce,
+                managed_buffer!(SLOT),
+            );
+        })
+        .assert_ok();
+
+    return unlock_time;
+}
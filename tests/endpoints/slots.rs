@@ -0,0 +1,289 @@
+use customize_nft::constants::{
+    ENQUEUE_PRICE, ERR_ATTRIBUTES_DO_NOT_MATCH_COLLECTION_SLOTS, ERR_SLOT_HAS_REGISTERED_ITEMS,
+    ERR_TOO_MANY_SLOTS_EQUIPPED, UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::slots::SlotsModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use customize_nft::Equip;
+use elrond_wasm::elrond_codec::multi_types::MultiValue4;
+use elrond_wasm::types::MultiValueEncoded;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+#[test]
+fn get_slots_sorted_by_layer() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_slots(&[(b"hat", 3), (b"background", 0), (b"skin", 1)]);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let slots: Vec<_> = sc
+                .get_slots(managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                .into_iter()
+                .map(|kvp| kvp.into_tuple())
+                .collect();
+
+            assert_eq!(
+                slots,
+                vec![
+                    (managed_buffer!(b"background"), 0),
+                    (managed_buffer!(b"skin"), 1),
+                    (managed_buffer!(b"hat"), 3)
+                ]
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_register_item_on_unknown_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_slots(&[(b"background", 0)]);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue4::from((
+                    managed_buffer!(b"hat"),
+                    managed_buffer!(b"Pirate Hat"),
+                    managed_token_id!(b"HAT-a1a1a1"),
+                    1,
+                )));
+
+                sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
+            },
+        )
+        .assert_user_error("The slot hat is not registered.");
+}
+
+#[test]
+fn panic_if_unregister_slot_with_items() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_slots(&[(b"hat", 0)]);
+    setup.register_and_fill_item(
+        b"hat",
+        b"Pirate Hat",
+        b"HAT-a1a1a1",
+        1,
+        &TestItemAttributes {},
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unregister_slot(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"hat"),
+                );
+            },
+        )
+        .assert_user_error(ERR_SLOT_HAS_REGISTERED_ITEMS);
+}
+
+#[test]
+fn panic_if_customize_equippable_with_unknown_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.register_and_fill_item(
+        b"hat",
+        b"Pirate Hat",
+        b"HAT-a1a1a1",
+        1,
+        &TestItemAttributes {},
+    );
+    setup.add_random_item_to_user(b"HAT-a1a1a1", 1, 1);
+    setup.register_slots(&[(b"background", 0)]);
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (b"HAT-a1a1a1", 1),
+    ]));
+
+    tx_result.assert_user_error("The slot hat is not registered.");
+}
+
+#[test]
+fn unequip_from_unregistered_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        b"HAT-a1a1a1",
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.register_slots(&[(b"background", 0)]);
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[b"hat"],
+    );
+
+    tx_result.assert_ok();
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, b"HAT-a1a1a1", 1),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn unequip_when_above_lowered_max_slots() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        b"HAT-a1a1a1",
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.register_and_fill_item(
+        b"background",
+        b"Blue",
+        b"BG-a1a1a1",
+        1,
+        &TestItemAttributes {},
+    );
+    setup.add_random_item_to_user(b"BG-a1a1a1", 1, 1);
+    setup.set_uri_of_items(
+        &[(b"hat", b"Pirate Hat"), (b"background", b"Blue")],
+        b"https://ipfs.io/ipfs/hat-and-background",
+    );
+    setup.set_uri_of_items(
+        &[
+            (b"hat", b"Pirate Hat"),
+            (b"background", UNEQUIPPED_ITEM_NAME),
+        ],
+        b"https://ipfs.io/ipfs/hat",
+    );
+
+    let (opt_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (b"BG-a1a1a1", 1),
+    ]));
+    tx_result.assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_max_slots_per_equippable(managed_token_id!(EQUIPPABLE_TOKEN_ID), 1);
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_nonce.unwrap())]),
+        &[b"background"],
+    );
+
+    tx_result.assert_ok();
+}
+
+#[test]
+fn panic_if_equip_more_than_max_slots() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_slots(&[(b"hat", 1), (b"background", 0)]);
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        b"HAT-a1a1a1",
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.register_and_fill_item(
+        b"background",
+        b"Blue",
+        b"BG-a1a1a1",
+        1,
+        &TestItemAttributes {},
+    );
+    setup.add_random_item_to_user(b"BG-a1a1a1", 1, 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_max_slots_per_equippable(managed_token_id!(EQUIPPABLE_TOKEN_ID), 1);
+            },
+        )
+        .assert_ok();
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (b"BG-a1a1a1", 1),
+    ]));
+
+    tx_result.assert_user_error(ERR_TOO_MANY_SLOTS_EQUIPPED);
+}
+
+#[test]
+fn panic_if_render_unknown_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_slots(&[(b"background", 0)]);
+    setup.add_enqueue_price_balance_to_owner();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(ENQUEUE_PRICE),
+            |sc| {
+                let attributes = EquippableAttributes::<DebugApi>::new(&[Item {
+                    slot: managed_buffer!(b"hat"),
+                    name: managed_buffer!(b"Pirate Hat"),
+                }]);
+
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                );
+            },
+        )
+        .assert_user_error(ERR_ATTRIBUTES_DO_NOT_MATCH_COLLECTION_SLOTS);
+}
@@ -0,0 +1,218 @@
+use customize_nft::constants::{
+    ERR_NOT_ENOUGH_AVAILABLE_STOCK, ERR_NOT_ENOUGH_SUPPLIER_DEPOSITS,
+    ERR_TOKEN_NOT_ASSIGNED_TO_SUPPLIER,
+};
+use customize_nft::libs::inventory::InventoryModule;
+use customize_nft::libs::suppliers::SuppliersModule;
+use customize_nft::structs::item::Item;
+use customize_nft::structs::token::Token;
+use customize_nft::Equip;
+use elrond_wasm::elrond_codec::multi_types::{MultiValue2, MultiValue3};
+use elrond_wasm::types::{BigUint, ManagedAddress, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_NAME: &[u8] = b"Pirate Hat";
+
+#[test]
+fn supplier_fills_and_withdraws() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    register_hat(&mut setup);
+    approve_supplier(&mut setup, true);
+
+    fill_as_supplier(&mut setup, 3).assert_ok();
+
+    // the owner cannot withdraw the stock of the supplier
+    withdraw(&mut setup, true, 1).assert_user_error(ERR_NOT_ENOUGH_AVAILABLE_STOCK);
+
+    withdraw(&mut setup, false, 4).assert_user_error(ERR_NOT_ENOUGH_SUPPLIER_DEPOSITS);
+    withdraw(&mut setup, false, 2).assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.second_user_address, HAT_ID, 1),
+        rust_biguint!(2)
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let supplier_tokens: Vec<_> = sc
+                .get_supplier_tokens(ManagedAddress::from_address(&setup.second_user_address))
+                .into_iter()
+                .map(|row| row.into_tuple())
+                .collect();
+
+            assert_eq!(
+                supplier_tokens,
+                vec![(managed_token_id!(HAT_ID), 1u64, BigUint::from(1u32))]
+            );
+            assert_eq!(
+                sc.get_inventory(managed_token_id!(HAT_ID), 1).stock,
+                BigUint::from(1u32)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn supplier_withdraws_only_the_stock_left() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    register_hat(&mut setup);
+    approve_supplier(&mut setup, true);
+
+    fill_as_supplier(&mut setup, 3).assert_ok();
+
+    // an unequipped hat is paid out of the stock deposited by the supplier
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.record_payout(
+                    &managed_token_id!(testing_utils::EQUIPPABLE_TOKEN_ID),
+                    &Item {
+                        slot: managed_buffer!(b"hat"),
+                        name: managed_buffer!(HAT_NAME),
+                    },
+                    &Token::new(managed_token_id!(HAT_ID), 1),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let supplier_tokens: Vec<_> = sc
+                .get_supplier_tokens(ManagedAddress::from_address(&setup.second_user_address))
+                .into_iter()
+                .map(|row| row.into_tuple())
+                .collect();
+
+            assert_eq!(
+                supplier_tokens,
+                vec![(managed_token_id!(HAT_ID), 1u64, BigUint::from(2u32))]
+            );
+        })
+        .assert_ok();
+
+    withdraw(&mut setup, false, 3).assert_user_error(ERR_NOT_ENOUGH_SUPPLIER_DEPOSITS);
+    withdraw(&mut setup, false, 2).assert_ok();
+}
+
+#[test]
+fn panic_if_token_not_assigned() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    register_hat(&mut setup);
+    approve_supplier(&mut setup, false);
+
+    fill_as_supplier(&mut setup, 1).assert_user_error(ERR_TOKEN_NOT_ASSIGNED_TO_SUPPLIER);
+}
+
+fn register_hat<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    withdraw(setup, true, 2).assert_ok();
+}
+
+fn approve_supplier<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    assign_hat: bool,
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let supplier = setup.second_user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_supplier(ManagedAddress::from_address(&supplier));
+
+                if assign_hat {
+                    let mut tokens = MultiValueEncoded::new();
+                    tokens.push(MultiValue2::from((managed_token_id!(HAT_ID), 1u64)));
+
+                    sc.assign_tokens_to_supplier(ManagedAddress::from_address(&supplier), tokens);
+                }
+            },
+        )
+        .assert_ok();
+}
+
+fn fill_as_supplier<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    quantity: u64,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.second_user_address,
+        HAT_ID,
+        1,
+        &rust_biguint!(quantity),
+        &TestItemAttributes {},
+    );
+
+    return setup.blockchain_wrapper.execute_esdt_transfer(
+        &setup.second_user_address,
+        &setup.cf_wrapper,
+        HAT_ID,
+        1,
+        &rust_biguint!(quantity),
+        |sc| {
+            sc.fill();
+        },
+    );
+}
+
+fn withdraw<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    as_owner: bool,
+    amount: u64,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let caller = if as_owner {
+        setup.owner_address.clone()
+    } else {
+        setup.second_user_address.clone()
+    };
+
+    return setup.blockchain_wrapper.execute_tx(
+        &caller,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut items = MultiValueEncoded::new();
+            items.push(MultiValue3::from((
+                managed_token_id!(HAT_ID),
+                1u64,
+                BigUint::from(amount),
+            )));
+
+            if as_owner {
+                sc.withdraw_items(items);
+            } else {
+                sc.withdraw_supplier_items(items);
+            }
+        },
+    );
+}
@@ -0,0 +1,103 @@
+use customize_nft::constants::{ERR_ADD_QUANTITY_ROLE_NOT_SET_FOR_ITEM, UNEQUIPPED_ITEM_NAME};
+use customize_nft::structs::supply_mode::SupplyMode;
+use customize_nft::Equip;
+use elrond_wasm::types::EsdtLocalRole;
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"hat";
+const ITEM_NAME: &[u8] = b"Pirate Hat";
+const ITEM_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn burn_on_equip_and_mint_on_unequip() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, 1, &TestItemAttributes {});
+    set_item_supply_mode(&mut setup, SupplyMode::Mint).assert_ok();
+
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(ITEM_ID, 1, 1);
+    setup.set_uri_of_items(&[(SLOT, ITEM_NAME)], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (ITEM_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    // the item received has been burned
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(setup.cf_wrapper.address_ref(), ITEM_ID, 1),
+        rust_biguint!(2)
+    );
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_new_nonce.unwrap())]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    // the item sent back has been minted
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(setup.cf_wrapper.address_ref(), ITEM_ID, 1),
+        rust_biguint!(2)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, 1),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn panic_if_roles_not_set() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, 1, &TestItemAttributes {});
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.cf_wrapper.address_ref(),
+        ITEM_ID,
+        &[EsdtLocalRole::NftBurn],
+    );
+
+    set_item_supply_mode(&mut setup, SupplyMode::Mint)
+        .assert_user_error(ERR_ADD_QUANTITY_ROLE_NOT_SET_FOR_ITEM);
+}
+
+fn set_item_supply_mode<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    supply_mode: SupplyMode,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.set_item_supply_mode(
+                managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                managed_buffer!(SLOT),
+                managed_buffer!(ITEM_NAME),
+                supply_mode,
+            );
+        },
+    );
+}
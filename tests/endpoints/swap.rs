@@ -0,0 +1,206 @@
+use customize_nft::constants::{
+    ERR_CANNOT_SWAP_EMPTY_SLOTS, ERR_CONFLICTING_SLOTS_EQUIPPED, ERR_SWAP_NEEDS_TWO_EQUIPPABLES,
+    UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::customize::CustomizeModule;
+use customize_nft::libs::slots::SlotsModule;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::item::Item;
+use elrond_wasm::types::{ManagedBuffer, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::{TxInputESDT, TxResult};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, New, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const FIRST_NONCE: u64 = 30;
+const SECOND_NONCE: u64 = 31;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const SUIT_ID: &[u8] = b"SUIT-a1a1a1";
+
+#[test]
+fn swap_hat_to_empty_equippable() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        FIRST_NONCE,
+        HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        b"Pirate Hat",
+    );
+    setup.create_empty_equippable(SECOND_NONCE);
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+
+    let (opt_nonces, tx_result) = swap(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, FIRST_NONCE),
+            (EQUIPPABLE_TOKEN_ID, SECOND_NONCE),
+        ]),
+        &[b"hat"],
+    );
+    tx_result.assert_ok();
+
+    let (first_nonce, second_nonce) = opt_nonces.unwrap();
+
+    let mut emptied_attributes = EquippableAttributes::<DebugApi>::empty();
+    emptied_attributes.empty_slot(&managed_buffer!(b"hat"));
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        first_nonce,
+        &rust_biguint!(1),
+        Option::Some(&emptied_attributes),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        second_nonce,
+        &rust_biguint!(1),
+        Option::Some(&EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(b"hat"),
+            name: managed_buffer!(b"Pirate Hat"),
+        }])),
+    );
+
+    setup.assert_is_burn(EQUIPPABLE_TOKEN_ID, FIRST_NONCE);
+    setup.assert_is_burn(EQUIPPABLE_TOKEN_ID, SECOND_NONCE);
+
+    // the item never left the equippables
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, HAT_ID, 1),
+        rust_biguint!(0)
+    );
+}
+
+#[test]
+fn panic_if_only_one_equippable() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.create_empty_equippable(FIRST_NONCE);
+
+    let (_, tx_result) = swap(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, FIRST_NONCE)]),
+        &[b"hat"],
+    );
+    tx_result.assert_user_error(ERR_SWAP_NEEDS_TWO_EQUIPPABLES);
+}
+
+#[test]
+fn panic_if_both_slots_are_empty() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_slots(&[(b"hat", 0)]);
+    setup.create_empty_equippable(FIRST_NONCE);
+    setup.create_empty_equippable(SECOND_NONCE);
+
+    let (_, tx_result) = swap(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, FIRST_NONCE),
+            (EQUIPPABLE_TOKEN_ID, SECOND_NONCE),
+        ]),
+        &[b"hat"],
+    );
+    tx_result.assert_user_error(ERR_CANNOT_SWAP_EMPTY_SLOTS);
+}
+
+#[test]
+fn panic_if_swap_equips_conflicting_slots() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"suit", b"Tuxedo", SUIT_ID, 1, &TestItemAttributes {});
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut slots = MultiValueEncoded::<DebugApi, ManagedBuffer<DebugApi>>::new();
+                slots.push(managed_buffer!(b"hat"));
+                slots.push(managed_buffer!(b"suit"));
+
+                sc.set_exclusion_group(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(b"hat-suit"),
+                    slots,
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        FIRST_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(b"hat"),
+            name: managed_buffer!(b"Pirate Hat"),
+        }]),
+    );
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.first_user_address,
+        EQUIPPABLE_TOKEN_ID,
+        SECOND_NONCE,
+        &rust_biguint!(1),
+        &EquippableAttributes::<DebugApi>::new(&[Item {
+            slot: managed_buffer!(b"suit"),
+            name: managed_buffer!(b"Tuxedo"),
+        }]),
+    );
+
+    let (_, tx_result) = swap(
+        &mut setup,
+        testing_utils::create_esdt_transfers(&[
+            (EQUIPPABLE_TOKEN_ID, FIRST_NONCE),
+            (EQUIPPABLE_TOKEN_ID, SECOND_NONCE),
+        ]),
+        &[b"suit"],
+    );
+    tx_result.assert_user_error(ERR_CONFLICTING_SLOTS_EQUIPPED);
+}
+
+fn swap<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    transfers: Vec<TxInputESDT>,
+    slots: &[&[u8]],
+) -> (Option<(u64, u64)>, TxResult)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let mut opt_sc_result = Option::None;
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_multi_transfer(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        &transfers,
+        |sc| {
+            let mut slots_managed = MultiValueEncoded::<DebugApi, ManagedBuffer<DebugApi>>::new();
+
+            for slot in slots {
+                slots_managed.push(managed_buffer!(slot));
+            }
+
+            opt_sc_result = Option::Some(sc.swap(slots_managed).into_tuple());
+        },
+    );
+
+    return (opt_sc_result, tx_result);
+}
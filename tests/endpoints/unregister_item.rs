@@ -0,0 +1,207 @@
+use customize_nft::constants::{ERR_ITEM_NOT_REGISTERED, UNEQUIPPED_ITEM_NAME};
+use customize_nft::libs::inventory::InventoryModule;
+use customize_nft::libs::storage::StorageModule;
+use customize_nft::structs::item::Item;
+use customize_nft::structs::registry_change::RegistryChangeKind;
+use customize_nft::{EndpointWrappers, Equip};
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::MultiValueEncoded;
+use elrond_wasm_debug::{
+    managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const SLOT: &[u8] = b"hat";
+const ITEM_NAME: &[u8] = b"Pirate Hat";
+const ITEM_ID: &[u8] = b"HAT-a1a1a1";
+const ITEM_NONCE: u64 = 1;
+
+#[test]
+fn works() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(SLOT, ITEM_NAME, ITEM_ID, ITEM_NONCE, &TestItemAttributes {});
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unregister_item(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                );
+
+                let item = Item {
+                    slot: managed_buffer!(SLOT),
+                    name: managed_buffer!(ITEM_NAME),
+                };
+                assert_eq!(
+                    sc.get_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item)
+                        .is_none(),
+                    true
+                );
+
+                let changes: Vec<_> = sc.get_registry_changes().into_iter().collect();
+                assert_eq!(changes.len(), 1);
+                assert_eq!(changes[0].kind, RegistryChangeKind::Unregister);
+                assert_eq!(changes[0].old_item, item);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_not_registered() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unregister_item(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                );
+            },
+        )
+        .assert_user_error(ERR_ITEM_NOT_REGISTERED);
+}
+
+#[test]
+fn unequip_after_unregistering() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        ITEM_ID,
+        ITEM_NONCE,
+        SLOT,
+        TestItemAttributes {},
+        ITEM_NAME,
+    );
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    unregister(&mut setup);
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, ITEM_NONCE),
+        rust_biguint!(1)
+    );
+}
+
+#[test]
+fn unequip_after_unregistering_without_stock() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        ITEM_ID,
+        ITEM_NONCE,
+        SLOT,
+        TestItemAttributes {},
+        ITEM_NAME,
+    );
+    setup.set_uri_of_items(
+        &[(SLOT, UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue3::from((
+                    managed_token_id!(ITEM_ID),
+                    ITEM_NONCE,
+                    managed_biguint!(2),
+                )));
+
+                sc.withdraw_items(items);
+            },
+        )
+        .assert_ok();
+
+    unregister(&mut setup);
+
+    // the slot is emptied, but there is no token to send back
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[SLOT],
+    );
+    tx_result.assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, ITEM_ID, ITEM_NONCE),
+        rust_biguint!(0)
+    );
+}
+
+#[test]
+fn panic_if_not_the_owner() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.call_unregister_item();
+            },
+        )
+        .assert_user_error("Endpoint can only be called by owner");
+}
+
+fn unregister<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unregister_item(
+                    managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    managed_buffer!(SLOT),
+                    managed_buffer!(ITEM_NAME),
+                );
+            },
+        )
+        .assert_ok();
+}
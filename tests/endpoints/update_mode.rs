@@ -0,0 +1,137 @@
+use customize_nft::constants::{
+    ERR_UPDATE_ATTRIBUTES_ROLE_NOT_SET_FOR_EQUIPPABLE, UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::structs::update_mode::UpdateMode;
+use customize_nft::Equip;
+use elrond_wasm::contract_base::ContractBase;
+use elrond_wasm::types::EsdtLocalRole;
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn customize_in_place_keeps_the_nonce() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.cf_wrapper.address_ref(),
+        EQUIPPABLE_TOKEN_ID,
+        &[EsdtLocalRole::NftUpdateAttributes, EsdtLocalRole::NftAddUri],
+    );
+    set_update_mode(&mut setup, UpdateMode::InPlace).assert_ok();
+
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+    assert_eq!(opt_new_nonce, Option::Some(EQUIPPABLE_NONCE));
+
+    let (opt_new_nonce, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE)]),
+        &[b"hat"],
+    );
+    tx_result.assert_ok();
+    assert_eq!(opt_new_nonce, Option::Some(EQUIPPABLE_NONCE));
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_esdt_balance(
+            &setup.first_user_address,
+            EQUIPPABLE_TOKEN_ID,
+            EQUIPPABLE_NONCE
+        ),
+        rust_biguint!(1)
+    );
+    assert_updated_attributes(&mut setup, b"hat:unequipped", 2);
+}
+
+#[test]
+fn equip_in_place() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.cf_wrapper.address_ref(),
+        EQUIPPABLE_TOKEN_ID,
+        &[EsdtLocalRole::NftUpdateAttributes, EsdtLocalRole::NftAddUri],
+    );
+    set_update_mode(&mut setup, UpdateMode::InPlace).assert_ok();
+
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"hat", b"Pirate Hat")], b"https://ipfs.io/ipfs/hat");
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    assert_updated_attributes(&mut setup, b"hat:Pirate Hat", 1);
+}
+
+#[test]
+fn panic_if_update_roles_not_set() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    set_update_mode(&mut setup, UpdateMode::InPlace)
+        .assert_user_error(ERR_UPDATE_ATTRIBUTES_ROLE_NOT_SET_FOR_EQUIPPABLE);
+}
+
+fn set_update_mode<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    update_mode: UpdateMode,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.set_update_mode(managed_token_id!(EQUIPPABLE_TOKEN_ID), update_mode);
+        },
+    );
+}
+
+/// The testing framework keeps the metadata the user had before sending the equippable,
+/// so the updated metadata is read from the contract account, where the update happened.
+fn assert_updated_attributes<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    expected_attributes: &[u8],
+    expected_uris_count: usize,
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let esdt_data = sc.blockchain().get_esdt_token_data(
+                &sc.blockchain().get_sc_address(),
+                &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                EQUIPPABLE_NONCE,
+            );
+
+            assert_eq!(esdt_data.attributes, managed_buffer!(expected_attributes));
+            assert_eq!(esdt_data.uris.len(), expected_uris_count);
+        })
+        .assert_ok();
+}
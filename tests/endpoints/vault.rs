@@ -0,0 +1,101 @@
+use customize_nft::constants::{ERR_NOT_ENOUGH_IN_VAULT, ERR_VAULT_ACCEPTS_ONLY_ITEMS};
+use customize_nft::libs::vault::VaultModule;
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::{ManagedAddress, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_biguint, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+
+#[test]
+fn deposit_and_withdraw() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", b"Pirate Hat", HAT_ID, 1, &TestItemAttributes {});
+    setup.add_random_item_to_user(HAT_ID, 1, 3);
+
+    deposit(&mut setup, HAT_ID, 3).assert_ok();
+
+    let owner = setup.first_user_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let vault: Vec<_> = sc
+                .get_vault(ManagedAddress::from_address(&owner))
+                .into_iter()
+                .map(|row| row.into_tuple())
+                .collect();
+
+            assert_eq!(
+                vault,
+                vec![(managed_token_id!(HAT_ID), 1u64, managed_biguint!(3))]
+            );
+        })
+        .assert_ok();
+
+    withdraw(&mut setup, 2).assert_ok();
+    withdraw(&mut setup, 2).assert_user_error(ERR_NOT_ENOUGH_IN_VAULT);
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.first_user_address, HAT_ID, 1),
+        rust_biguint!(2)
+    );
+}
+
+#[test]
+fn panic_if_deposit_is_not_an_item() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+
+    deposit(&mut setup, HAT_ID, 1).assert_user_error(ERR_VAULT_ACCEPTS_ONLY_ITEMS);
+}
+
+fn deposit<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    token_id: &[u8],
+    amount: u64,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_esdt_transfer(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        token_id,
+        1,
+        &rust_biguint!(amount),
+        |sc| {
+            sc.deposit_to_vault();
+        },
+    );
+}
+
+fn withdraw<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    amount: u64,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.first_user_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut items = MultiValueEncoded::new();
+            items.push(MultiValue3::from((
+                managed_token_id!(HAT_ID),
+                1u64,
+                managed_biguint!(amount),
+            )));
+
+            sc.withdraw_from_vault(items);
+        },
+    );
+}
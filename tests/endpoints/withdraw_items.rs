@@ -0,0 +1,104 @@
+use customize_nft::constants::ERR_NOT_ENOUGH_AVAILABLE_STOCK;
+use customize_nft::libs::inventory::EndpointWrappers;
+use customize_nft::libs::inventory::InventoryModule;
+use customize_nft::structs::token::Token;
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::{BigUint, MultiValueEncoded};
+use elrond_wasm_debug::tx_mock::TxResult;
+use elrond_wasm_debug::{managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_NAME: &[u8] = b"Pirate Hat";
+
+#[test]
+fn works() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+
+    withdraw_items(&mut setup, 2).assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(&setup.owner_address, HAT_ID, 1),
+        rust_biguint!(2)
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let inventory = sc.get_inventory_of(&Token::new(managed_token_id!(HAT_ID), 1));
+
+            assert_eq!(inventory.deposited, BigUint::from(2u32));
+            assert_eq!(inventory.withdrawn, BigUint::from(2u32));
+            assert_eq!(inventory.stock, BigUint::zero());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn panic_if_withdraw_equipped_items() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"hat", HAT_NAME)], b"https://ipfs.io/ipfs/hat");
+
+    let (_, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    withdraw_items(&mut setup, 3).assert_user_error(ERR_NOT_ENOUGH_AVAILABLE_STOCK);
+    withdraw_items(&mut setup, 2).assert_ok();
+}
+
+#[test]
+fn panic_if_not_the_owner() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.first_user_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.call_withdraw_items();
+            },
+        )
+        .assert_user_error("Endpoint can only be called by owner");
+}
+
+fn withdraw_items<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    amount: u64,
+) -> TxResult
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.cf_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut items = MultiValueEncoded::new();
+            items.push(MultiValue3::from((
+                managed_token_id!(HAT_ID),
+                1u64,
+                BigUint::from(amount),
+            )));
+
+            sc.withdraw_items(items);
+        },
+    );
+}
@@ -101,7 +101,13 @@ where
                 &self.cf_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let minted_nonce = sc.update_equippable(mint_nonce, &get_attributes());
+                    let minted_nonce = sc.update_equippable(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        mint_nonce,
+                        &get_attributes(),
+                        &sc.blockchain().get_caller(),
+                        false,
+                    );
 
                     let minted_nft = sc.blockchain().get_esdt_token_data(
                         &sc.blockchain().get_sc_address(),
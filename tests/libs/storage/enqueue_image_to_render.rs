@@ -3,13 +3,15 @@ use customize_nft::{
         ENQUEUE_PRICE, ERR_CANNOT_ENQUEUE_IMAGE_BECAUSE_ALREADY_RENDERED,
         ERR_RENDER_ALREADY_IN_QUEUE,
     },
-    libs::equippable_uris::EquippableUrisModule,
+    libs::{equippable_uris::EquippableUrisModule, uris::UrisModule},
     structs::{equippable_attributes::EquippableAttributes, item::Item},
 };
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
 use crate::testing_utils::{self, New};
 
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
 #[test]
 fn works() {
     let mut setup = testing_utils::setup(customize_nft::contract_obj);
@@ -28,10 +30,22 @@ fn works() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
             },
         )
         .assert_ok();
@@ -61,22 +75,42 @@ fn enqueue_two_differents_attributes() {
                 }]);
                 let name_b = managed_buffer!(b"Equippable #513");
 
-                sc.enqueue_image_to_render(&attributes_a, &name_a);
-                sc.enqueue_image_to_render(&attributes_b, &name_b);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_a,
+                    &name_a,
+                );
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes_b,
+                    &name_b,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 2);
                 assert_eq!(
-                    sc.attributes_to_render_by_name().contains_key(&name_a),
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    2
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name_a),
                     true
                 );
                 assert_eq!(
-                    sc.attributes_to_render_by_name().contains_key(&name_b),
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name_b),
                     true
                 );
 
                 let mut iter = sc.get_images_to_render().into_iter();
-                assert_eq!(iter.next().unwrap().into_tuple(), (attributes_a, name_a));
-                assert_eq!(iter.next().unwrap().into_tuple(), (attributes_b, name_b));
+                assert_eq!(
+                    iter.next().unwrap().into_tuple(),
+                    (managed_token_id!(EQUIPPABLE_TOKEN_ID), attributes_a, name_a)
+                );
+                assert_eq!(
+                    iter.next().unwrap().into_tuple(),
+                    (managed_token_id!(EQUIPPABLE_TOKEN_ID), attributes_b, name_b)
+                );
                 assert_eq!(iter.next().is_none(), true);
             },
         )
@@ -106,7 +140,11 @@ fn panic_if_already_rendererer() {
             &setup.cf_wrapper,
             &rust_biguint!(ENQUEUE_PRICE),
             |sc| {
-                sc.enqueue_image_to_render(&get_attributes().0, &get_attributes().1);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &get_attributes().0,
+                    &get_attributes().1,
+                );
             },
         )
         .assert_user_error(ERR_CANNOT_ENQUEUE_IMAGE_BECAUSE_ALREADY_RENDERED);
@@ -130,11 +168,27 @@ fn panic_if_already_in_queue() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
-                sc.enqueue_image_to_render(&attributes, &name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
 
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
             },
         )
         .assert_user_error(ERR_RENDER_ALREADY_IN_QUEUE);
@@ -187,7 +241,11 @@ fn panic_if_attributes_already_in_queue_but_in_another_order() {
             &setup.cf_wrapper,
             &rust_biguint!(ENQUEUE_PRICE),
             |sc| {
-                sc.enqueue_image_to_render(&image_to_render_a().0, &image_to_render_a().1);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &image_to_render_a().0,
+                    &image_to_render_a().1,
+                );
             },
         )
         .assert_ok();
@@ -199,7 +257,11 @@ fn panic_if_attributes_already_in_queue_but_in_another_order() {
             &setup.cf_wrapper,
             &rust_biguint!(ENQUEUE_PRICE),
             |sc| {
-                sc.enqueue_image_to_render(&image_to_render_b().0, &image_to_render_b().1);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &image_to_render_b().0,
+                    &image_to_render_b().1,
+                );
             },
         )
         .assert_user_error(ERR_RENDER_ALREADY_IN_QUEUE);
@@ -1,15 +1,17 @@
 use customize_nft::{
-    libs::equippable_uris::EquippableUrisModule,
+    libs::{equippable_uris::EquippableUrisModule, uris::UrisModule},
     structs::{equippable_attributes::EquippableAttributes, item::Item},
 };
-use elrond_wasm::{elrond_codec::multi_types::MultiValue3, types::MultiValueEncoded};
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm::types::MultiValueEncoded;
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
 use crate::{
     args_set_cid_of,
     testing_utils::{self, New},
 };
 
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
 /// The eq of nft_attributes doesn't work on storage. We write these tests to help us fix this.
 
 #[test]
@@ -41,7 +43,11 @@ fn should_return_cid() {
                 ));
 
                 assert_eq!(
-                    sc.get_uri_of(&get_attributes().0, &get_attributes().1),
+                    sc.get_uri_of(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &get_attributes().0,
+                        &get_attributes().1
+                    ),
                     cid_buffer
                 )
             },
@@ -113,7 +119,11 @@ fn should_return_cid_from_equivalent_but_not_exact_attr() {
                 ));
 
                 assert_eq!(
-                    sc.get_uri_of(&image_to_render.0, &image_to_render.1),
+                    sc.get_uri_of(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &image_to_render.0,
+                        &image_to_render.1
+                    ),
                     cid_buffer
                 )
             },
@@ -125,7 +135,11 @@ fn should_return_cid_from_equivalent_but_not_exact_attr() {
         .blockchain_wrapper
         .execute_query(&setup.cf_wrapper, |sc| {
             assert_eq!(
-                sc.get_uri_of(&get_attributes_reversed().0, &get_attributes_reversed().1),
+                sc.get_uri_of(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &get_attributes_reversed().0,
+                    &get_attributes_reversed().1
+                ),
                 managed_buffer!(cid_bytes)
             );
         })
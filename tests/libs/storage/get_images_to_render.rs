@@ -1,11 +1,14 @@
 use customize_nft::{
-    constants::ENQUEUE_PRICE, libs::equippable_uris::EquippableUrisModule,
+    constants::ENQUEUE_PRICE,
+    libs::{equippable_uris::EquippableUrisModule, uris::UrisModule},
     structs::equippable_attributes::EquippableAttributes,
 };
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
 use crate::testing_utils;
 
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
 #[test]
 fn returns_empty() {
     let mut setup = testing_utils::setup(customize_nft::contract_obj);
@@ -42,15 +45,30 @@ fn returns_one_after_one_enqueue() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
 
                 let enqueued = sc.get_images_to_render();
                 assert_eq!(enqueued.len(), 1);
 
                 let mut iter = enqueued.into_iter();
-                assert_eq!(iter.next().unwrap().into_tuple(), (attributes, name));
+                assert_eq!(
+                    iter.next().unwrap().into_tuple(),
+                    (managed_token_id!(EQUIPPABLE_TOKEN_ID), attributes, name)
+                );
                 assert_eq!(iter.next().is_none(), true);
             },
         )
@@ -75,8 +93,13 @@ fn returns_zero_after_one_dequeue() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
-                sc.attributes_to_render_by_name().remove(&name);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .remove(&name);
 
                 assert_eq!(sc.get_images_to_render().len(), 0);
             },
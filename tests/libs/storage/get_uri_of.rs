@@ -1,15 +1,17 @@
 use crate::testing_utils::New;
 use crate::{args_set_cid_of, testing_utils};
 use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::uris::UrisModule;
 use elrond_wasm::types::ManagedBuffer;
 use elrond_wasm::types::MultiValueEncoded;
-use elrond_wasm_debug::{managed_buffer, DebugApi};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, DebugApi};
 
 use customize_nft::structs::equippable_attributes::EquippableAttributes;
 use customize_nft::structs::item::Item;
-use elrond_wasm::elrond_codec::multi_types::MultiValue3;
 use elrond_wasm_debug::rust_biguint;
 
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
 #[test]
 fn build_url_with_no_associated_cid() {
     let mut setup = testing_utils::setup(customize_nft::contract_obj);
@@ -23,7 +25,7 @@ fn build_url_with_no_associated_cid() {
             }]);
             let name = managed_buffer!(b"Equippable #512");
 
-            let _ = sc.get_uri_of(&attributes, &name);
+            let _ = sc.get_uri_of(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &attributes, &name);
         })
         .assert_user_error(
             "There is no URI associated to the attributes hat:item name for Equippable #512.",
@@ -61,7 +63,11 @@ fn build_url_with_associated_cid() {
                     managed_buffer!(b"https://ipfs.io/ipfs/this is a CID")
                 ));
 
-                let url = sc.get_uri_of(&image_to_render.0, &image_to_render.1);
+                let url = sc.get_uri_of(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &image_to_render.0,
+                    &image_to_render.1,
+                );
 
                 assert_eq!(
                     url,
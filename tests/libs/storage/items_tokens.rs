@@ -0,0 +1,189 @@
+use customize_nft::{
+    libs::storage::StorageModule,
+    structs::{item::Item, token::Token},
+};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint};
+
+use crate::testing_utils::{self, EQUIPPABLE_TOKEN_ID};
+
+#[test]
+fn after_insert_should_returns_valid_values() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let token = Token::new(managed_token_id!(b"HAT-a1a1a1"), 1);
+                let item = Item {
+                    name: managed_buffer!(b"Pirate Hat"),
+                    slot: managed_buffer!(b"hat"),
+                };
+
+                assert_eq!(
+                    sc.insert_item_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item, &token),
+                    true
+                );
+
+                assert_eq!(
+                    sc.has_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item),
+                    true
+                );
+                assert_eq!(
+                    sc.has_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &token),
+                    true
+                );
+
+                assert_eq!(
+                    sc.get_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &token)
+                        .unwrap(),
+                    item
+                );
+                assert_eq!(
+                    sc.get_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item)
+                        .unwrap(),
+                    token
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn first_token_inserted_is_preferred() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let first_token = Token::new(managed_token_id!(b"HAT-a1a1a1"), 1);
+                let second_token = Token::new(managed_token_id!(b"HAT-b2b2b2"), 1);
+                let item = Item {
+                    name: managed_buffer!(b"Pirate Hat"),
+                    slot: managed_buffer!(b"hat"),
+                };
+
+                assert_eq!(
+                    sc.insert_item_token(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &item,
+                        &first_token
+                    ),
+                    true
+                );
+                assert_eq!(
+                    sc.insert_item_token(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &item,
+                        &second_token
+                    ),
+                    true
+                );
+                assert_eq!(
+                    sc.insert_item_token(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &item,
+                        &second_token
+                    ),
+                    false
+                );
+
+                assert_eq!(
+                    sc.get_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &first_token)
+                        .unwrap(),
+                    item
+                );
+                assert_eq!(
+                    sc.get_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &second_token)
+                        .unwrap(),
+                    item
+                );
+                assert_eq!(
+                    sc.get_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item)
+                        .unwrap(),
+                    first_token
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn remove_item_should_remove_its_tokens() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let first_token = Token::new(managed_token_id!(b"HAT-a1a1a1"), 1);
+                let second_token = Token::new(managed_token_id!(b"HAT-b2b2b2"), 1);
+                let item = Item {
+                    name: managed_buffer!(b"Pirate Hat"),
+                    slot: managed_buffer!(b"hat"),
+                };
+
+                sc.insert_item_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item, &first_token);
+                sc.insert_item_token(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &item,
+                    &second_token,
+                );
+                sc.remove_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item);
+
+                assert_eq!(
+                    sc.has_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item),
+                    false
+                );
+                assert_eq!(
+                    sc.has_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &first_token),
+                    false
+                );
+                assert_eq!(
+                    sc.has_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &second_token),
+                    false
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn empty_storage_should_returns_false() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let token = Token::new(managed_token_id!(b"HAT-a1a1a1"), 1);
+                let item = Item {
+                    name: managed_buffer!(b"Pirate Hat"),
+                    slot: managed_buffer!(b"hat"),
+                };
+
+                assert_eq!(
+                    sc.has_item(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &item),
+                    false
+                );
+                assert_eq!(
+                    sc.has_token(&managed_token_id!(EQUIPPABLE_TOKEN_ID), &token),
+                    false
+                );
+            },
+        )
+        .assert_ok();
+}
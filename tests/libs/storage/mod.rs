@@ -2,5 +2,5 @@ mod enqueue_image_to_render;
 mod get_cid_of;
 mod get_images_to_render;
 mod get_uri_of;
-mod map_items_tokens;
+mod items_tokens;
 mod set_uri_of;
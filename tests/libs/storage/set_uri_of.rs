@@ -2,18 +2,22 @@ use customize_nft::{
     constants::{
         ENQUEUE_PRICE, ERR_CANNOT_OVERRIDE_URI_OF_ATTRIBUTE, ERR_IMAGE_NOT_IN_RENDER_QUEUE,
     },
-    libs::equippable_uris::{EndpointWrappers, EquippableUrisModule, ERR_ATTRIBUTES_MISMATCH},
+    libs::{
+        equippable_uris::{EndpointWrappers, EquippableUrisModule, ERR_ATTRIBUTES_MISMATCH},
+        uris::UrisModule,
+    },
     structs::{equippable_attributes::EquippableAttributes, item::Item},
 };
-use elrond_wasm::elrond_codec::multi_types::MultiValue3;
 use elrond_wasm::types::MultiValueEncoded;
-use elrond_wasm_debug::{managed_buffer, rust_biguint, DebugApi};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
 
 use crate::{
     args_set_cid_of,
     testing_utils::{self, New},
 };
 
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+
 #[test]
 fn should_set_if_empty() {
     DebugApi::dummy();
@@ -44,8 +48,12 @@ fn should_set_if_empty() {
                 ));
 
                 assert_eq!(
-                    sc.uris_of_attributes(&get_image_to_render().0, &get_image_to_render().1)
-                        .get(),
+                    sc.uris_of_attributes(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &get_image_to_render().0,
+                        &get_image_to_render().1
+                    )
+                    .get(),
                     managed_buffer!(cid_bytes)
                 );
             },
@@ -113,7 +121,12 @@ fn panic_if_not_in_render_queue() {
                 ));
 
                 assert_eq!(
-                    sc.uris_of_attributes(&attributes, &name).get(),
+                    sc.uris_of_attributes(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &attributes,
+                        &name
+                    )
+                    .get(),
                     managed_buffer!(cid_bytes)
                 );
             },
@@ -152,8 +165,12 @@ fn panic_if_override_previously_set_uri() {
                     managed_buffer!(first_cid_bytes)
                 ));
                 assert_eq!(
-                    sc.uris_of_attributes(&image_to_render.0, &image_to_render.1)
-                        .get(),
+                    sc.uris_of_attributes(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &image_to_render.0,
+                        &image_to_render.1
+                    )
+                    .get(),
                     managed_buffer!(first_cid_bytes)
                 );
             },
@@ -216,9 +233,21 @@ fn should_remove_enqueued_image_to_render() {
                 let attributes = EquippableAttributes::<DebugApi>::empty();
                 let name = managed_buffer!(b"Equippable #512");
 
-                sc.enqueue_image_to_render(&attributes, &name);
-                assert_eq!(sc.attributes_to_render_by_name().len(), 1);
-                assert_eq!(sc.attributes_to_render_by_name().contains_key(&name), true);
+                sc.enqueue_image_to_render(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    &attributes,
+                    &name,
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
+                    1
+                );
+                assert_eq!(
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .contains_key(&name),
+                    true
+                );
 
                 sc.set_uri_of_attributes(args_set_cid_of!(
                     attributes,
@@ -227,7 +256,8 @@ fn should_remove_enqueued_image_to_render() {
                 ));
 
                 assert_eq!(
-                    sc.attributes_to_render_by_name().len(),
+                    sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                        .len(),
                     0,
                     "The enqueud image to render should be has been removed."
                 );
@@ -0,0 +1,78 @@
+use customize_nft::constants::PERMANENT_LOCK;
+use customize_nft::structs::{equippable_attributes::EquippableAttributes, item::Item};
+use elrond_wasm::{
+    elrond_codec::{TopDecode, TopEncode},
+    types::ManagedBuffer,
+};
+use elrond_wasm_debug::{managed_buffer, DebugApi};
+
+use crate::testing_utils::New;
+
+#[test]
+fn encode_and_decode_locks() {
+    DebugApi::dummy();
+
+    let mut attributes = EquippableAttributes::new(&[
+        Item::<DebugApi> {
+            name: managed_buffer!(b"Pirate Hat"),
+            slot: managed_buffer!(b"hat"),
+        },
+        Item::<DebugApi> {
+            name: managed_buffer!(b"Anchor"),
+            slot: managed_buffer!(b"tattoo"),
+        },
+    ]);
+    attributes.lock_slot(&managed_buffer!(b"hat"), 1700000000);
+    attributes.lock_slot(&managed_buffer!(b"tattoo"), PERMANENT_LOCK);
+
+    let mut encoded = ManagedBuffer::<DebugApi>::new();
+    let _ = attributes.top_encode(&mut encoded);
+
+    assert_eq!(
+        encoded,
+        managed_buffer!(b"hat:Pirate Hat:1700000000;tattoo:Anchor:permanent")
+    );
+
+    let decoded = EquippableAttributes::<DebugApi>::top_decode(encoded).unwrap();
+
+    assert_eq!(decoded, attributes);
+    assert_eq!(
+        decoded.get_unlock_timestamp(&managed_buffer!(b"hat")),
+        1700000000
+    );
+    assert_eq!(
+        decoded.get_unlock_timestamp(&managed_buffer!(b"tattoo")),
+        PERMANENT_LOCK
+    );
+}
+
+#[test]
+fn setting_an_item_removes_the_lock() {
+    DebugApi::dummy();
+
+    let mut attributes = EquippableAttributes::new(&[Item::<DebugApi> {
+        name: managed_buffer!(b"Pirate Hat"),
+        slot: managed_buffer!(b"hat"),
+    }]);
+    attributes.lock_slot(&managed_buffer!(b"hat"), 1700000000);
+
+    attributes.set_item(&managed_buffer!(b"hat"), Some(managed_buffer!(b"Cap")));
+
+    assert_eq!(attributes.get_unlock_timestamp(&managed_buffer!(b"hat")), 0);
+}
+
+#[test]
+fn without_locks() {
+    DebugApi::dummy();
+
+    let unlocked_attributes = EquippableAttributes::new(&[Item::<DebugApi> {
+        name: managed_buffer!(b"Pirate Hat"),
+        slot: managed_buffer!(b"hat"),
+    }]);
+
+    let mut attributes = unlocked_attributes.clone();
+    attributes.lock_slot(&managed_buffer!(b"hat"), PERMANENT_LOCK);
+
+    assert_ne!(attributes, unlocked_attributes);
+    assert_eq!(attributes.without_locks(), unlocked_attributes);
+}
@@ -4,4 +4,5 @@ mod encode_tests;
 mod equals;
 mod get_items_tests;
 mod is_empty_tests;
+mod lock_tests;
 mod set_tests;
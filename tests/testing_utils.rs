@@ -4,9 +4,11 @@ elrond_wasm::derive_imports!();
 use std::ops::Deref;
 use std::u8;
 
-use customize_nft::constants::ENQUEUE_PRICE;
+use customize_nft::constants::{ENQUEUE_PRICE, UNEQUIPPED_ITEM_NAME};
 use customize_nft::libs::customize::CustomizeModule;
 use customize_nft::libs::equippable_uris::EquippableUrisModule;
+use customize_nft::libs::slots::SlotsModule;
+use customize_nft::libs::uris::UrisModule;
 use customize_nft::structs::equippable_attributes::EquippableAttributes;
 use customize_nft::structs::item::Item;
 use customize_nft::*;
@@ -58,7 +60,13 @@ macro_rules! args_set_cid_of {
     ($attr: expr, $name:expr, $cid: expr) => {{
         let mut _val = MultiValueEncoded::new();
 
-        let element = MultiValue3::from(($attr.clone(), $name.clone(), $cid.clone()));
+        let element = elrond_wasm::elrond_codec::multi_types::MultiValue4::from((
+            // EQUIPPABLE_TOKEN_ID, not referenced by path as this file is also built as its own test target
+            elrond_wasm_debug::managed_token_id!(b"PENG-ae5a"),
+            $attr.clone(),
+            $name.clone(),
+            $cid.clone(),
+        ));
         _val.push(element);
 
         _val
@@ -157,7 +165,7 @@ where
                         item_nonce,
                     )));
 
-                    sc.register_item(items);
+                    sc.register_item(managed_token_id!(EQUIPPABLE_TOKEN_ID), items);
                 },
             )
             .assert_ok();
@@ -195,6 +203,55 @@ where
         );
     }
 
+    /// Set the URI of the attributes made of the items (slot, name) provided.
+    /// Use UNEQUIPPED_ITEM_NAME as name to set an empty slot.
+    pub fn set_uri_of_items(&mut self, items: &[(&[u8], &[u8])], uri: &[u8]) {
+        self.blockchain_wrapper
+            .execute_tx(
+                &self.owner_address,
+                &self.cf_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut attributes = EquippableAttributes::<DebugApi>::empty();
+                    for (slot, name) in items {
+                        let opt_name = if name == &UNEQUIPPED_ITEM_NAME {
+                            Option::None
+                        } else {
+                            Option::Some(managed_buffer!(name))
+                        };
+
+                        attributes.set_item(&managed_buffer!(slot), opt_name);
+                    }
+
+                    sc.uris_of_attributes(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &attributes,
+                        &managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                    )
+                    .set(managed_buffer!(uri));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn register_slots(&mut self, slots: &[(&[u8], u32)]) {
+        self.blockchain_wrapper
+            .execute_tx(
+                &self.owner_address,
+                &self.cf_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut managed_slots = MultiValueEncoded::new();
+                    for (slot, layer) in slots {
+                        managed_slots.push(MultiValue2::from((managed_buffer!(slot), *layer)));
+                    }
+
+                    sc.register_slots(managed_token_id!(EQUIPPABLE_TOKEN_ID), managed_slots);
+                },
+            )
+            .assert_ok();
+    }
+
     pub fn add_random_item_to_user(&mut self, token_id: &[u8], nonce: u64, quantity: u64) {
         self.blockchain_wrapper.set_nft_balance(
             &self.first_user_address,
@@ -352,7 +409,11 @@ where
                 &self.cf_wrapper,
                 &rust_biguint!(ENQUEUE_PRICE),
                 |sc| {
-                    sc.enqueue_image_to_render(&get_image_to_render().0, &get_image_to_render().1);
+                    sc.enqueue_image_to_render(
+                        &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                        &get_image_to_render().0,
+                        &get_image_to_render().1,
+                    );
                 },
             )
             .assert_ok();
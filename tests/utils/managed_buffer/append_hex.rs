@@ -0,0 +1,36 @@
+use customize_nft::utils::managed_buffer_utils::ManagedBufferUtils;
+use elrond_wasm::types::ManagedBuffer;
+use elrond_wasm_debug::DebugApi;
+
+#[test]
+fn append_hex() {
+    DebugApi::dummy();
+
+    let mut buffer = ManagedBuffer::<DebugApi>::new_from_bytes(b"0x");
+    buffer.append_hex(&ManagedBuffer::new_from_bytes(&[0x00, 0x0f, 0xa1, 0xff]));
+
+    assert_eq!(buffer, ManagedBuffer::new_from_bytes(b"0x000fa1ff"));
+}
+
+#[test]
+fn starts_with() {
+    DebugApi::dummy();
+
+    let buffer = ManagedBuffer::<DebugApi>::new_from_bytes(b"https://example.com/a.json");
+
+    assert_eq!(
+        buffer.starts_with(&ManagedBuffer::new_from_bytes(b"https://example.com/")),
+        true
+    );
+    assert_eq!(buffer.starts_with(&ManagedBuffer::new()), true);
+    assert_eq!(
+        buffer.starts_with(&ManagedBuffer::new_from_bytes(b"https://ipfs.io/")),
+        false
+    );
+    assert_eq!(
+        buffer.starts_with(&ManagedBuffer::new_from_bytes(
+            b"https://example.com/a.json/too-long"
+        )),
+        false
+    );
+}
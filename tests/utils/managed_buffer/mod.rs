@@ -1,4 +1,6 @@
+mod append_hex;
 mod compare;
 mod contains;
 mod load_512_bytes;
+mod parse_u64;
 mod split;
@@ -0,0 +1,48 @@
+use customize_nft::utils::managed_buffer_utils::ManagedBufferUtils;
+use elrond_wasm::types::ManagedBuffer;
+use elrond_wasm_debug::DebugApi;
+
+#[test]
+fn parse_decimal_numbers() {
+    DebugApi::dummy();
+
+    assert_eq!(
+        ManagedBuffer::<DebugApi>::new_from_bytes(b"0").parse_u64(),
+        Some(0)
+    );
+    assert_eq!(
+        ManagedBuffer::<DebugApi>::new_from_bytes(b"1700000000").parse_u64(),
+        Some(1700000000)
+    );
+    assert_eq!(
+        ManagedBuffer::<DebugApi>::new_from_bytes(b"18446744073709551615").parse_u64(),
+        Some(u64::MAX)
+    );
+}
+
+#[test]
+fn return_none_if_not_a_number() {
+    DebugApi::dummy();
+
+    assert_eq!(ManagedBuffer::<DebugApi>::new().parse_u64(), None);
+    assert_eq!(
+        ManagedBuffer::<DebugApi>::new_from_bytes(b"12a").parse_u64(),
+        None
+    );
+    assert_eq!(
+        ManagedBuffer::<DebugApi>::new_from_bytes(b"18446744073709551616").parse_u64(),
+        None
+    );
+}
+
+#[test]
+fn append_u64_is_the_reverse_of_parse() {
+    DebugApi::dummy();
+
+    for value in [0u64, 7, 1700000000, u64::MAX] {
+        let mut buffer = ManagedBuffer::<DebugApi>::new();
+        buffer.append_u64(value);
+
+        assert_eq!(buffer.parse_u64(), Some(value));
+    }
+}
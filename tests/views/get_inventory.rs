@@ -0,0 +1,162 @@
+use customize_nft::constants::UNEQUIPPED_ITEM_NAME;
+use customize_nft::libs::inventory::InventoryModule;
+use elrond_wasm::elrond_codec::multi_types::MultiValue3;
+use elrond_wasm::types::{BigUint, MultiValueEncoded};
+use elrond_wasm_debug::{managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_NAME: &[u8] = b"Pirate Hat";
+const COLLAB_ID: &[u8] = b"COLLAB-b2b2b2";
+
+#[test]
+fn track_deposits_equips_and_payouts() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(HAT_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"hat", HAT_NAME)], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (HAT_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let inventory = sc.get_inventory(managed_token_id!(HAT_ID), 1);
+
+            assert_eq!(inventory.deposited, BigUint::from(2u32));
+            assert_eq!(inventory.equipped, BigUint::from(1u32));
+            assert_eq!(inventory.stock, BigUint::from(3u32));
+            assert_eq!(inventory.get_available(), BigUint::from(2u32));
+        })
+        .assert_ok();
+
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_new_nonce.unwrap())]),
+        &[b"hat"],
+    );
+    tx_result.assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let inventory = sc.get_inventory(managed_token_id!(HAT_ID), 1);
+
+            assert_eq!(inventory.paid_out, BigUint::from(1u32));
+            assert_eq!(inventory.equipped, BigUint::zero());
+            assert_eq!(inventory.stock, BigUint::from(2u32));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn release_equipped_token_when_unequipping_another_one() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(b"hat", HAT_NAME, COLLAB_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.add_random_item_to_user(COLLAB_ID, 1, 1);
+    setup.set_uri_of_items(&[(b"hat", HAT_NAME)], b"https://ipfs.io/ipfs/hat");
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    let (opt_new_nonce, tx_result) = setup.equip(testing_utils::create_esdt_transfers(&[
+        (EQUIPPABLE_TOKEN_ID, EQUIPPABLE_NONCE),
+        (COLLAB_ID, 1),
+    ]));
+    tx_result.assert_ok();
+
+    // the preferred token is paid out
+    let (_, tx_result) = setup.customize(
+        testing_utils::create_esdt_transfers(&[(EQUIPPABLE_TOKEN_ID, opt_new_nonce.unwrap())]),
+        &[b"hat"],
+    );
+    tx_result.assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let hat_inventory = sc.get_inventory(managed_token_id!(HAT_ID), 1);
+            assert_eq!(hat_inventory.paid_out, BigUint::from(1u32));
+            assert_eq!(hat_inventory.equipped, BigUint::zero());
+            assert_eq!(hat_inventory.get_available(), BigUint::from(1u32));
+
+            let collab_inventory = sc.get_inventory(managed_token_id!(COLLAB_ID), 1);
+            assert_eq!(collab_inventory.equipped, BigUint::zero());
+            assert_eq!(collab_inventory.get_available(), BigUint::from(3u32));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn get_low_stock_items() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    setup.register_and_fill_item(
+        b"beak",
+        b"Golden",
+        b"BEAK-b2b2b2",
+        1,
+        &TestItemAttributes {},
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_low_stock_threshold(BigUint::from(1u32));
+
+                let mut items = MultiValueEncoded::new();
+                items.push(MultiValue3::from((
+                    managed_token_id!(HAT_ID),
+                    1u64,
+                    BigUint::from(1u32),
+                )));
+                sc.withdraw_items(items);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            assert_eq!(
+                sc.get_stock(managed_token_id!(EQUIPPABLE_TOKEN_ID)).len(),
+                2
+            );
+
+            let low_stock_items: Vec<_> = sc
+                .get_low_stock_items(managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                .into_iter()
+                .map(|row| row.into_tuple())
+                .collect();
+
+            assert_eq!(
+                low_stock_items,
+                vec![(managed_token_id!(HAT_ID), 1u64, BigUint::from(1u32))]
+            );
+        })
+        .assert_ok();
+}
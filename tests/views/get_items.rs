@@ -1,7 +1,7 @@
 use customize_nft::Equip;
 use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint};
 
-use crate::testing_utils::{self, TestItemAttributes};
+use crate::testing_utils::{self, TestItemAttributes, EQUIPPABLE_TOKEN_ID};
 
 #[test]
 fn ok_if_owner() {
@@ -28,7 +28,7 @@ fn ok_if_owner() {
             &setup.cf_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let output = sc.get_items();
+                let output = sc.get_items(managed_token_id!(EQUIPPABLE_TOKEN_ID));
 
                 let mut iter = output.into_iter();
 
@@ -38,7 +38,8 @@ fn ok_if_owner() {
                         managed_buffer!(slot),
                         managed_buffer!(item_name),
                         managed_token_id!(TOKEN),
-                        NONCE
+                        NONCE,
+                        false
                     )
                 );
                 assert_eq!(iter.next().is_none(), true);
@@ -1 +1,3 @@
+mod get_inventory;
 mod get_items;
+mod simulate_customize;
@@ -0,0 +1,271 @@
+use customize_nft::constants::{
+    ERR_CANNOT_UNEQUIP_EMPTY_SLOT, ERR_CONTRACT_PAUSED, ERR_RENDER_ALREADY_IN_QUEUE,
+    UNEQUIPPED_ITEM_NAME,
+};
+use customize_nft::libs::cooldowns::CooldownsModule;
+use customize_nft::libs::escrow::EscrowModule;
+use customize_nft::libs::pause::PauseModule;
+use customize_nft::libs::simulation::SimulationModule;
+use customize_nft::libs::uris::UrisModule;
+use customize_nft::structs::customize_simulation::CustomizeSimulation;
+use customize_nft::structs::equippable_attributes::EquippableAttributes;
+use customize_nft::structs::token::Token;
+use elrond_wasm::elrond_codec::multi_types::MultiValue2;
+use elrond_wasm::types::{ManagedAddress, ManagedBuffer, ManagedVec, MultiValueEncoded};
+use elrond_wasm_debug::{managed_buffer, managed_token_id, rust_biguint, DebugApi};
+
+use crate::testing_utils::{self, EquipSetup, TestItemAttributes};
+
+const EQUIPPABLE_TOKEN_ID: &[u8] = testing_utils::EQUIPPABLE_TOKEN_ID;
+const EQUIPPABLE_NONCE: u64 = 30;
+
+const HAT_ID: &[u8] = b"HAT-a1a1a1";
+const HAT_NAME: &[u8] = b"Pirate Hat";
+
+#[test]
+fn simulate_equip() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.set_uri_of_items(&[(b"hat", HAT_NAME)], b"https://ipfs.io/ipfs/hat");
+
+    simulate_customize(&mut setup, &[], &[(HAT_ID, 1)], |simulation| {
+        assert_eq!(simulation.attributes, managed_buffer!(b"hat:Pirate Hat"));
+        assert_eq!(simulation.sent_back.len(), 0);
+        assert_eq!(simulation.has_uri, true);
+        assert_eq!(simulation.is_held, false);
+        assert_eq!(simulation.error, ManagedBuffer::new());
+    });
+}
+
+#[test]
+fn simulate_unequip_without_uri() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        HAT_NAME,
+    );
+
+    simulate_customize(&mut setup, &[b"hat"], &[], |simulation| {
+        let mut expected_sent_back = ManagedVec::<DebugApi, Token<DebugApi>>::new();
+        expected_sent_back.push(Token::new(managed_token_id!(HAT_ID), 1));
+
+        assert_eq!(simulation.attributes, managed_buffer!(b"hat:unequipped"));
+        assert_eq!(simulation.sent_back, expected_sent_back);
+        assert_eq!(simulation.has_uri, false);
+        assert_eq!(
+            simulation.error,
+            managed_buffer!(
+                b"There is no URI associated to the attributes hat:unequipped for PENG-ae5a."
+            )
+        );
+    });
+}
+
+#[test]
+fn simulate_unregistered_item() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.set_uri_of_items(
+        &[(b"hat", UNEQUIPPED_ITEM_NAME)],
+        b"https://ipfs.io/ipfs/empty",
+    );
+
+    simulate_customize(&mut setup, &[], &[(HAT_ID, 1)], |simulation| {
+        assert_eq!(simulation.has_uri, false);
+        assert_eq!(
+            simulation.error,
+            managed_buffer!(b"The item you are equipping HAT-a1a1a1 1 is not registered.")
+        );
+    });
+}
+
+#[test]
+fn simulate_unequip_empty_slot() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+
+    simulate_customize(&mut setup, &[b"hat"], &[], |simulation| {
+        assert_eq!(
+            simulation.error,
+            managed_buffer!(ERR_CANNOT_UNEQUIP_EMPTY_SLOT.as_bytes())
+        );
+    });
+}
+
+#[test]
+fn simulate_while_paused() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.register_and_fill_item(b"hat", HAT_NAME, HAT_ID, 1, &TestItemAttributes {});
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.set_uri_of_items(&[(b"hat", HAT_NAME)], b"https://ipfs.io/ipfs/hat");
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause();
+            },
+        )
+        .assert_ok();
+
+    simulate_customize(&mut setup, &[], &[(HAT_ID, 1)], |simulation| {
+        assert_eq!(
+            simulation.error,
+            managed_buffer!(ERR_CONTRACT_PAUSED.as_bytes())
+        );
+    });
+}
+
+#[test]
+fn simulate_reports_slot_error_before_cooldown() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_empty_equippable(EQUIPPABLE_NONCE);
+    setup.blockchain_wrapper.set_block_timestamp(1_000);
+
+    let owner = setup.first_user_address.clone();
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_customization_cooldowns(managed_token_id!(EQUIPPABLE_TOKEN_ID), 0, 100);
+                sc.record_customization(
+                    &managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                    EQUIPPABLE_NONCE,
+                    EQUIPPABLE_NONCE,
+                    &ManagedAddress::from_address(&owner),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(1_001);
+
+    // customize fails on the empty slot before checking the cooldowns
+    simulate_customize(&mut setup, &[b"hat"], &[], |simulation| {
+        assert_eq!(
+            simulation.error,
+            managed_buffer!(ERR_CANNOT_UNEQUIP_EMPTY_SLOT.as_bytes())
+        );
+    });
+}
+
+#[test]
+fn simulate_hold_in_escrow() {
+    let mut setup = testing_utils::setup(customize_nft::contract_obj);
+
+    DebugApi::dummy();
+    setup.create_equippable_with_registered_item(
+        EQUIPPABLE_NONCE,
+        HAT_ID,
+        1,
+        b"hat",
+        TestItemAttributes {},
+        HAT_NAME,
+    );
+    enable_escrow(&mut setup);
+
+    simulate_customize(&mut setup, &[b"hat"], &[], |simulation| {
+        assert_eq!(simulation.has_uri, false);
+        assert_eq!(simulation.is_held, true);
+        assert_eq!(simulation.error, ManagedBuffer::new());
+    });
+
+    // another image of the equippable is waiting to be rendered
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.attributes_to_render_by_name(&managed_token_id!(EQUIPPABLE_TOKEN_ID))
+                    .insert(
+                        managed_buffer!(EQUIPPABLE_TOKEN_ID),
+                        EquippableAttributes::<DebugApi>::empty(),
+                    );
+            },
+        )
+        .assert_ok();
+
+    simulate_customize(&mut setup, &[b"hat"], &[], |simulation| {
+        assert_eq!(simulation.is_held, true);
+        assert_eq!(
+            simulation.error,
+            managed_buffer!(ERR_RENDER_ALREADY_IN_QUEUE.as_bytes())
+        );
+    });
+}
+
+fn enable_escrow<EquippableObjBuilder>(setup: &mut EquipSetup<EquippableObjBuilder>)
+where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.cf_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_escrow_enabled(managed_token_id!(EQUIPPABLE_TOKEN_ID), true);
+            },
+        )
+        .assert_ok();
+}
+
+fn simulate_customize<EquippableObjBuilder>(
+    setup: &mut EquipSetup<EquippableObjBuilder>,
+    unequip_slots: &[&[u8]],
+    items: &[(&[u8], u64)],
+    assert_simulation: impl Fn(CustomizeSimulation<DebugApi>),
+) where
+    EquippableObjBuilder: 'static + Copy + Fn() -> customize_nft::ContractObj<DebugApi>,
+{
+    let owner = setup.first_user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.cf_wrapper, |sc| {
+            let mut unequip_slots_managed = ManagedVec::new();
+            for slot in unequip_slots {
+                unequip_slots_managed.push(managed_buffer!(slot));
+            }
+
+            let mut items_managed = MultiValueEncoded::new();
+            for (token_id, nonce) in items {
+                items_managed.push(MultiValue2::from((managed_token_id!(*token_id), *nonce)));
+            }
+
+            assert_simulation(sc.simulate_customize(
+                ManagedAddress::from_address(&owner),
+                managed_token_id!(EQUIPPABLE_TOKEN_ID),
+                EQUIPPABLE_NONCE,
+                unequip_slots_managed,
+                items_managed,
+            ));
+        })
+        .assert_ok();
+}
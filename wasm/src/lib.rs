@@ -7,17 +7,110 @@
 elrond_wasm_node::wasm_endpoints! {
     customize_nft
     (
+        addEquippableCollection
+        addGeneratedUri
+        addItemRequirement
+        addPauser
+        addProxy
+        addSupplier
+        applyLoadout
+        assignTokensToSupplier
         authorizeAddressToSetUris
+        cancelCustomization
         claim
+        claimFeeRevenues
+        claimTreasury
         customize
+        customizeBatch
+        customizeFor
+        depositFeeCredit
+        depositToVault
         fill
+        getCustomizationCooldowns
+        getEquippableCollections
+        getExclusionGroups
+        getFeeCredit
+        getFeeRevenues
+        getFeeSchedules
+        getFeeSplit
+        getGeneratedUris
         getImagesToRender
+        getInventory
+        getItemRequirements
+        getItemSupplyMode
         getItems
+        getLoadouts
+        getLowStockItems
+        getNextCustomizationTime
+        getPauseState
+        getPendingCustomizations
+        getPlaceholderNonces
+        getPlaceholderUri
+        getPreferredToken
+        getProxies
+        getRegistryChanges
+        getRenderPrices
+        getSlotUnlockTime
+        getSlots
+        getSlotsDefaults
+        getStock
+        getSupplierTokens
+        getSuppliers
+        getTreasury
+        getUpdateMode
         getUriOf
+        getVault
+        isEscrowEnabled
+        migrateLegacyInventory
+        migrateLegacyItems
+        migrateLegacyRenderQueue
         overrideRoyalties
+        pause
+        pauseEndpoints
+        refreshUri
         registerItem
+        registerSlots
+        remapItem
+        removeEquippableCollection
+        removeExclusionGroup
+        removeFeeSchedule
+        removeGeneratedUri
+        removeItemRequirement
+        removeLoadout
+        removePauser
+        removeProxy
+        removeRenderPrice
+        removeSlotDefault
+        removeSupplier
         renderImage
+        saveLoadout
+        setCustomizationCooldowns
+        setEscrowEnabled
+        setExclusionGroup
+        setFeeBeneficiaries
+        setFeeSchedule
+        setItemConsumable
+        setItemLockDuration
+        setItemSupplyMode
+        setLowStockThreshold
+        setMaxSlotsPerEquippable
+        setPlaceholderUri
+        setPreferredToken
+        setRenderPrice
+        setSlotDefault
+        setUpdateMode
         setUriOfAttributes
+        simulateCustomize
+        swap
+        unassignTokensFromSupplier
+        unpause
+        unpauseEndpoints
+        unregisterItem
+        unregisterSlot
+        withdrawFeeCredit
+        withdrawFromVault
+        withdrawItems
+        withdrawSupplierItems
     )
 }
 